@@ -1,6 +1,7 @@
 #![allow(incomplete_features)]
 #![feature(const_generics)]
 #![feature(const_evaluatable_checked)]
+#![feature(array_value_iter)]
 
 mod types;
 pub use types::*;
@@ -11,7 +12,10 @@ mod util;
 #[cfg(test)]
 mod tests {
     mod vector {
-        use crate::vector;
+        use crate::{
+            assert_approx_eq, assert_vector_approx_eq, vector, Vector, VectorError, VectorXY,
+            VectorXYZ, VectorXYZW, Zero,
+        };
         #[test]
         fn vector_new() {
             let vector = vector!(0, 44, 2);
@@ -40,10 +44,7 @@ mod tests {
         #[test]
         fn vector_normalized() {
             let vector = vector!(3.0f32, 4.0f32);
-            assert_eq!(
-                vector.normalized().unwrap(),
-                vector!(3.0f32 / 5.0f32, 4.0f32 / 5.0f32)
-            );
+            assert_vector_approx_eq!(vector.normalized().unwrap(), vector!(0.6f32, 0.8f32));
         }
 
         #[test]
@@ -80,10 +81,222 @@ mod tests {
             a /= vector!(2, 2, 1);
             assert_eq!(a, vector!(1, 1, 99));
         }
+
+        #[test]
+        fn vector_swizzle() {
+            let a = vector!(1, 2, 3, 4);
+            assert_eq!(a.wzyx(), vector!(4, 3, 2, 1));
+            assert_eq!(a.xz(), vector!(1, 3));
+            assert_eq!(a.xxxx(), vector!(1, 1, 1, 1));
+            assert_eq!(vector!(1, 2, 3).zyx(), vector!(3, 2, 1));
+            assert_eq!(vector!(1, 2).yxy(), vector!(2, 1, 2));
+
+            let mut b = vector!(1, 2, 3);
+            b.set_xz(vector!(7, 9));
+            assert_eq!(b, vector!(7, 2, 9));
+            b.set_zyx(b);
+            assert_eq!(b, vector!(9, 2, 7));
+
+            assert_eq!(a.swizzle([3, 3, 0]).unwrap(), vector!(4, 4, 1));
+            assert_eq!(b.swizzle([1; 5]).unwrap(), vector!(2, 2, 2, 2, 2));
+            assert!(b.swizzle([0, 3]).is_err());
+            let c = vector!(5, 6, 7, 8);
+            assert_eq!(a.shuffle(&c, [0, 4, 1, 5]).unwrap(), vector!(1, 5, 2, 6));
+            assert!(a.shuffle(&c, [8]).is_err());
+
+            // The trait swizzles still work through generic code
+            fn yzw<V: VectorXYZW<i32>>(v: &V) -> Vector<i32, 3> {
+                v.yzw()
+            }
+            assert_eq!(yzw(&a), vector!(2, 3, 4));
+            assert_eq!(VectorXYZ::yz(&vector!(1, 2, 3)), vector!(2, 3));
+        }
+
+        #[test]
+        fn vector_resize() {
+            let a = vector!(1, 2, 3);
+            assert_eq!(a.truncate(), vector!(1, 2));
+            assert_eq!(a.extend(9), vector!(1, 2, 3, 9));
+            assert_eq!(a.resize::<5>(), vector!(1, 2, 3, 0, 0));
+            assert_eq!(a.resize::<2>(), vector!(1, 2));
+            assert_eq!(a.resize::<3>(), a);
+
+            let point = vector!(2.0, 4.0, 6.0);
+            assert_eq!(point.to_homogeneous_point(), vector!(2.0, 4.0, 6.0, 1.0));
+            assert_eq!(
+                point.to_homogeneous_direction(),
+                vector!(2.0, 4.0, 6.0, 0.0)
+            );
+            assert_eq!(
+                vector!(2.0, 4.0, 6.0, 2.0).from_homogeneous(),
+                vector!(1.0, 2.0, 3.0)
+            );
+            assert_eq!(point.to_homogeneous_point().from_homogeneous(), point);
+            assert_eq!(point.to_homogeneous_direction().from_homogeneous(), point);
+        }
+
+        #[test]
+        fn vector_combinators() {
+            let a = vector!(1, 2, 3);
+            let b = vector!(4, 5, 6);
+            assert_eq!(a.map(|x| x * 10), vector!(10, 20, 30));
+            assert_eq!(
+                a.map_with_index(|idx, x| idx as i32 - x),
+                vector!(-1, -1, -1)
+            );
+            assert_eq!(a.zip_with(b, |x, y| x * y), vector!(4, 10, 18));
+            assert_eq!(
+                a.zip3_with(b, vector!(1.0, 0.5, 0.0), |x, y, t| x as f32 * t + y as f32),
+                vector!(5.0, 6.0, 6.0)
+            );
+            assert_eq!(a.fold(0, |sum, x| sum + x), 6);
+            assert_eq!(b.reduce(|x, y| x.max(y)).unwrap(), 6);
+            assert!(a.any(|&x| x == 2));
+            assert!(!a.all(|&x| x > 1));
+
+            let mut c = a;
+            for x in &mut c {
+                *x *= 2;
+            }
+            assert_eq!(c, vector!(2, 4, 6));
+            assert_eq!(c.iter().rev().copied().collect::<Vec<_>>(), vec![6, 4, 2]);
+            assert_eq!((&c).into_iter().len(), 3);
+
+            // Strings check that components are moved out exactly once
+            let names = vector!(String::from("a"), String::from("b"), String::from("c"));
+            let mut iter = names.clone().into_iter();
+            assert_eq!(iter.next_back().unwrap(), "c");
+            drop(iter);
+            assert_eq!(
+                names.map(|name| name + "!"),
+                vector!("a!", "b!", "c!").map(String::from)
+            );
+
+            assert_eq!(Vector::<i32, 3>::try_from_iter(1..4).unwrap(), a);
+            assert_eq!(
+                Vector::<i32, 3>::try_from_iter(1..3),
+                Err(VectorError::IncorrectComponentCount)
+            );
+            assert!(Vector::<i32, 3>::try_from_iter(1..5).is_err());
+            assert!(Vector::<u32, 3>::try_from_iter(0..).is_err());
+
+            let vectors = [a, b, vector!(1, 1, 1)];
+            assert_eq!(vectors.iter().sum::<Vector<i32, 3>>(), vector!(6, 8, 10));
+            assert_eq!(vectors.iter().copied().product::<Vector<i32, 3>>(), b * a);
+            assert_eq!(
+                Vec::<Vector<f32, 2>>::new()
+                    .into_iter()
+                    .sum::<Vector<f32, 2>>(),
+                Vector::default()
+            );
+        }
+
+        #[test]
+        fn vector_component_math() {
+            let a = vector!(-1.5f32, 0.25, 2.0);
+            let b = vector!(1.0f32, 1.0, 1.0);
+            assert_eq!(a.min(&b), vector!(-1.5, 0.25, 1.0));
+            assert_eq!(a.max(&b), vector!(1.0, 1.0, 2.0));
+            assert_eq!(a.min_component(&0.0), vector!(-1.5, 0.0, 0.0));
+            assert_eq!(a.max_component(&0.0), vector!(0.0, 0.25, 2.0));
+            assert_eq!(a.clamp(&-b, &b), vector!(-1.0, 0.25, 1.0));
+            assert_eq!(a.clamp_component(&0.0, &1.0), vector!(0.0, 0.25, 1.0));
+            assert_eq!(vector!(-3, 0, 4).abs(), vector!(3, 0, 4));
+            assert_eq!(vector!(-3, 0, 4).signum(), vector!(-1, 0, 1));
+            assert_eq!(a.floor(), vector!(-2.0, 0.0, 2.0));
+            assert_eq!(a.ceil(), vector!(-1.0, 1.0, 2.0));
+            assert_eq!(a.round(), vector!(-2.0, 0.0, 2.0));
+            assert_eq!(a.fract(), vector!(-0.5, 0.25, 0.0));
+            assert_eq!(a.recip(), vector!(-1.0 / 1.5, 4.0, 0.5));
+            assert_eq!(vector!(4.0f64, 9.0).sqrt(), vector!(2.0, 3.0));
+            assert_eq!(
+                vector!(2.0f64, 3.0).pow(&vector!(3.0, 2.0)),
+                vector!(8.0, 9.0)
+            );
+            assert_eq!(vector!(2.0f64, 3.0).pow_component(&2.0), vector!(4.0, 9.0));
+            assert_eq!(vector!(0.0f64, 1.0).exp().ln(), vector!(0.0, 1.0));
+
+            assert_eq!(a.lerp(&b, 0.5), vector!(-0.25, 0.625, 1.5));
+            assert_eq!(
+                a.lerp_vector(&b, &vector!(0.0, 1.0, 0.5)),
+                vector!(-1.5, 1.0, 1.5)
+            );
+            assert_eq!(a.step(&vector!(0.0, 0.5, 2.0)), vector!(0.0, 0.0, 1.0));
+            assert_eq!(a.step_component(&0.0), vector!(0.0, 1.0, 1.0));
+            assert_eq!(
+                vector!(-1.0f32, 0.5, 0.75, 3.0).smoothstep_component(&0.0, &1.0),
+                vector!(0.0, 0.5, 0.84375, 1.0)
+            );
+
+            assert_eq!(a.min_element().unwrap(), -1.5);
+            assert_eq!(a.max_element().unwrap(), 2.0);
+            assert_eq!(vector!(2, 3, 4).sum(), 9);
+            assert_eq!(vector!(2, 3, 4).product(), 24);
+        }
+
+        #[test]
+        fn vector_geometry() {
+            let a = vector!(3.0f64, 4.0, 0.0);
+            let x = vector!(2.0f64, 0.0, 0.0);
+            assert_eq!(a.distance(&Vector::zero()).unwrap(), 5.0);
+            assert_eq!(a.distance2(&x).unwrap(), 17.0);
+            assert_eq!(a.project_onto(&x).unwrap(), vector!(3.0, 0.0, 0.0));
+            assert_eq!(a.reject_from(&x).unwrap(), vector!(0.0, 4.0, 0.0));
+            assert_eq!(
+                a.project_onto(&Vector::zero()),
+                Err(VectorError::ZeroLength)
+            );
+
+            let normal = vector!(0.0f64, 1.0, 0.0);
+            assert_eq!(
+                vector!(1.0, -1.0, 0.0).reflect(&normal),
+                vector!(1.0, 1.0, 0.0)
+            );
+            let incoming = vector!(1.0, -1.0, 0.0).normalized().unwrap();
+            assert_eq!(incoming.refract(&normal, 1.0).unwrap(), incoming);
+            let refracted = incoming.refract(&normal, 1.0 / 1.5).unwrap();
+            assert_approx_eq!(refracted.length().unwrap(), 1.0, 1e-12);
+            assert_approx_eq!(refracted[0], incoming[0] / 1.5, 1e-12);
+            assert!(incoming.refract(&normal, 1.5).is_none());
+
+            let angle = x.angle_between(&a).unwrap();
+            assert_approx_eq!(angle, (4.0f64).atan2(3.0), 1e-12);
+            assert_approx_eq!(x.angle_between(&-x).unwrap(), std::f64::consts::PI, 1e-12);
+            assert_eq!(x.angle_between(&x).unwrap(), 0.0);
+            assert!(x.angle_between(&Vector::zero()).is_err());
+
+            assert_eq!(a.try_normalized(1e-6).unwrap(), vector!(0.6, 0.8, 0.0));
+            assert!(vector!(1e-9, 0.0, 0.0).try_normalized(1e-6).is_err());
+            assert_eq!(Vector::<f64, 3>::zero().normalize_or_zero(), Vector::zero());
+            assert_eq!(a.clamp_length(0.0, 2.5), vector!(1.5, 2.0, 0.0));
+            assert_eq!(a.clamp_length(10.0, 20.0), vector!(6.0, 8.0, 0.0));
+            assert_eq!(a.clamp_length(1.0, 10.0), a);
+
+            assert_eq!(vector!(2.0, 1.0).perpendicular(), vector!(-1.0, 2.0));
+
+            let directions = [
+                vector!(0.0f64, 0.0, 1.0),
+                vector!(0.0, 0.0, -1.0),
+                vector!(1.0, 2.0, -3.0).normalized().unwrap(),
+                vector!(-0.5, 0.1, 0.2).normalized().unwrap(),
+            ];
+            for &n in directions.iter() {
+                let (b1, b2) = n.any_orthonormal_basis();
+                for (u, v) in [(n, b1), (n, b2), (b1, b2)].iter() {
+                    assert_approx_eq!(u.dot(v), 0.0, 1e-12);
+                }
+                assert_approx_eq!(b1.length().unwrap(), 1.0, 1e-12);
+                assert_approx_eq!(b2.length().unwrap(), 1.0, 1e-12);
+                assert_vector_approx_eq!(b1.cross(&b2), n, 1e-12);
+            }
+        }
     }
 
     mod matrix {
-        use crate::{vector, Matrix};
+        use crate::{
+            assert_approx_eq, assert_vector_approx_eq, vector, DepthRange, Handedness, Matrix,
+            MatrixError, ProjectionConfig, TransformMatrix, Vector,
+        };
         #[test]
         fn matrix_tests() {
             // Create 4x4 matrix of 32-bit floats
@@ -103,11 +316,14 @@ mod tests {
 
             // Multiply by a matrix with a position of (1.0, 0.0, 1.0) and a scale of (2.0, 2.0, 2.0)
             let multiplied = ident
-                * Matrix::new_position_scale(vector!(1.0, 0.0, 1.0), vector!(2.0, 2.0, 2.0))
-                    .unwrap();
+                * Matrix::<f32, 4, 4>::new_position_scale(
+                    vector!(1.0, 0.0, 1.0),
+                    vector!(2.0, 2.0, 2.0),
+                )
+                .unwrap();
 
-            // Test
-            assert_eq!(multiplied.position().unwrap(), vector!(3.0, 4.0, 7.0));
+            // Test (the right-hand matrix is applied first)
+            assert_eq!(multiplied.position().unwrap(), vector!(2.0, 2.0, 4.0));
         }
 
         #[test]
@@ -131,47 +347,1846 @@ mod tests {
                 ])
             );
         }
-    }
-    /*
-    mod nnet {
-        use crate::NNet;
 
         #[test]
-        fn nnet_new() {
-            // Create network
-            let mut seed = 13473; // Seed for random weights
-            let mut nn: NNet<3, 1, 2, 2> = NNet::new(&mut seed, 0.02).unwrap();
+        fn matrix_determinant() {
+            let matrix = Matrix::<f32, 2, 2>::from_array_array(&[[1.0, 2.0], [3.0, 4.0]]);
+            assert_eq!(matrix.determinant(), -2.0);
 
-            // Set up the training inputs and targets
-            let inputs = [
-                [0.0, 0.0, 0.0],
-                [0.0, 0.0, 1.0],
+            let matrix = Matrix::<f32, 3, 3>::from_array_array(&[
+                [2.0, 0.0, 1.0],
+                [1.0, 3.0, 2.0],
+                [1.0, 1.0, 2.0],
+            ]);
+            assert_eq!(matrix.determinant(), 6.0);
+
+            let matrix = Matrix::<f64, 4, 4>::new_position_scale(
+                vector!(1.0, 2.0, 3.0),
+                vector!(2.0, 4.0, 8.0),
+            )
+            .unwrap();
+            assert_eq!(matrix.determinant(), 64.0);
+
+            // Diagonal matrix with two columns swapped goes through the elimination path
+            let matrix = Matrix::<f64, 5, 5>::from_array_array(&[
+                [0.0, 3.0, 0.0, 0.0, 0.0],
+                [2.0, 0.0, 0.0, 0.0, 0.0],
+                [0.0, 0.0, 4.0, 0.0, 0.0],
+                [0.0, 0.0, 0.0, 5.0, 0.0],
+                [0.0, 0.0, 0.0, 0.0, 6.0],
+            ]);
+            assert_eq!(matrix.determinant(), -720.0);
+
+            let singular = Matrix::<f64, 5, 5>::from_array_array(&[[1.0; 5]; 5]);
+            assert_eq!(singular.determinant(), 0.0);
+
+            // Integer determinants stay exact through the fraction-free elimination
+            let matrix = Matrix::<i32, 3, 3>::from_array_array(&[[2, 0, 1], [1, 3, 2], [1, 1, 2]]);
+            assert_eq!(matrix.determinant(), 6);
+
+            let matrix = Matrix::<i64, 5, 5>::from_array_array(&[
+                [2, 1, 0, 0, 3],
+                [1, 3, 1, 0, 0],
+                [0, 1, 4, 1, 0],
+                [0, 0, 1, 5, 1],
+                [1, 0, 0, 1, 6],
+            ]);
+            let expected = Matrix::<f64, 5, 5>::from_array_array(&[
+                [2.0, 1.0, 0.0, 0.0, 3.0],
+                [1.0, 3.0, 1.0, 0.0, 0.0],
+                [0.0, 1.0, 4.0, 1.0, 0.0],
+                [0.0, 0.0, 1.0, 5.0, 1.0],
+                [1.0, 0.0, 0.0, 1.0, 6.0],
+            ])
+            .determinant();
+            assert_eq!(matrix.determinant() as f64, expected.round());
+        }
+
+        #[test]
+        fn matrix_inverse() {
+            let matrix = Matrix::<f32, 2, 2>::from_array_array(&[[1.0, 2.0], [3.0, 4.0]]);
+            assert_eq!(
+                matrix.inverse().unwrap(),
+                Matrix::from_array_array(&[[-2.0, 1.0], [1.5, -0.5]])
+            );
+
+            let matrix = Matrix::<f32, 4, 4>::new_position_scale(
+                vector!(1.0, 2.0, 3.0),
+                vector!(2.0, 4.0, 8.0),
+            )
+            .unwrap();
+            assert_eq!(
+                matrix.inverse().unwrap(),
+                Matrix::from_array_array(&[
+                    [0.5, 0.0, 0.0, 0.0],
+                    [0.0, 0.25, 0.0, 0.0],
+                    [0.0, 0.0, 0.125, 0.0],
+                    [-0.5, -0.5, -0.375, 1.0],
+                ])
+            );
+
+            let matrix = Matrix::<f64, 3, 3>::from_array_array(&[
+                [2.0, 0.0, 1.0],
+                [1.0, 3.0, 2.0],
+                [1.0, 1.0, 2.0],
+            ]);
+            assert_approx_eq!(matrix * matrix.inverse().unwrap(), Matrix::identity(), 1e-9);
+
+            let matrix = Matrix::<f64, 5, 5>::from_array_array(&[
+                [0.0, 3.0, 1.0, 0.0, 2.0],
+                [2.0, 0.5, 0.0, 1.0, 0.0],
+                [1.0, 0.0, 4.0, 0.0, 1.0],
+                [0.0, 2.0, 0.0, 5.0, 0.0],
+                [1.0, 0.0, 3.0, 0.0, 6.0],
+            ]);
+            assert_approx_eq!(matrix * matrix.inverse().unwrap(), Matrix::identity(), 1e-9);
+
+            let singular = Matrix::<f32, 4, 4>::from_array_array(&[[1.0; 4]; 4]);
+            assert_eq!(singular.inverse(), Err(MatrixError::Singular));
+            let singular = Matrix::<f64, 5, 5>::from_array_array(&[[1.0; 5]; 5]);
+            assert_eq!(singular.inverse(), Err(MatrixError::Singular));
+        }
+
+        #[test]
+        fn matrix_mul_shapes() {
+            // 2x3 and 3x2 matrices, stored as columns
+            let a = Matrix::<i32, 3, 2>::from_array_array(&[[1, 4], [2, 5], [3, 6]]);
+            let b = Matrix::<i32, 2, 3>::from_array_array(&[[7, 9, 11], [8, 10, 12]]);
+            assert_eq!(
+                a * b,
+                Matrix::<i32, 2, 2>::from_array_array(&[[58, 139], [64, 154]])
+            );
+            assert_eq!(
+                b * a,
+                Matrix::<i32, 3, 3>::from_array_array(&[[39, 49, 59], [54, 68, 82], [69, 87, 105]])
+            );
+
+            // 2x3 by a single column
+            let c = Matrix::<i32, 1, 3>::from_array_array(&[[1, 0, -1]]);
+            assert_eq!(a.mul_matrix(&c), Matrix::from_array_array(&[[-2, -2]]));
+
+            // Translation then scale is not scale then translation
+            let translation = Matrix::<f32, 4, 4>::new_position(vector!(1.0, 2.0, 3.0)).unwrap();
+            let scale = Matrix::<f32, 4, 4>::new_scale(vector!(2.0, 2.0, 2.0)).unwrap();
+            assert_eq!(
+                (translation * scale).position().unwrap(),
+                vector!(1.0, 2.0, 3.0)
+            );
+            assert_eq!(
+                (scale * translation).position().unwrap(),
+                vector!(2.0, 4.0, 6.0)
+            );
+        }
+
+        #[test]
+        fn matrix_resize() {
+            let a = Matrix::<i32, 3, 3>::from_array_array(&[[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+            let b = a.resize::<4, 4>();
+            assert_eq!(
+                b,
+                Matrix::from_array_array(&[[1, 2, 3, 0], [4, 5, 6, 0], [7, 8, 9, 0], [0, 0, 0, 1]])
+            );
+            assert_eq!(b.resize::<3, 3>(), a);
+            assert_eq!(
+                a.resize::<2, 3>(),
+                Matrix::from_array_array(&[[1, 2, 3], [4, 5, 6]])
+            );
+        }
+
+        #[test]
+        fn matrix_vector_mul() {
+            // Three columns of two rows
+            let matrix = Matrix::<i32, 3, 2>::from_array_array(&[[1, 4], [2, 5], [3, 6]]);
+            assert_eq!(matrix * vector!(1, 0, -1), vector!(-2, -2));
+            assert_eq!(matrix * vector!(2, 1, 1), vector!(7, 19));
+            assert_eq!(vector!(1, -1) * matrix, vector!(-3, -3, -3));
+            assert_eq!(vector!(2, 1) * matrix, vector!(6, 9, 12));
+        }
+
+        #[test]
+        fn matrix_transform() {
+            let matrix = Matrix::<f32, 4, 4>::new_position_scale(
+                vector!(1.0, 2.0, 3.0),
+                vector!(2.0, 2.0, 2.0),
+            )
+            .unwrap();
+            assert_eq!(
+                matrix.transform_point(vector!(1.0, 1.0, 1.0)),
+                vector!(3.0, 4.0, 5.0)
+            );
+            assert_eq!(
+                matrix.transform_vector(vector!(1.0, 1.0, 1.0)),
+                vector!(2.0, 2.0, 2.0)
+            );
+            assert_eq!(
+                matrix.transform_homogeneous(vector!(1.0, 1.0, 1.0)),
+                vector!(3.0, 4.0, 5.0)
+            );
+
+            // Points on the near and far planes end up at depth 0 and 1
+            let projection = Matrix::<f32, 4, 4>::projection(1.0, 1.0, 1.0, 10.0).unwrap();
+            let near = projection.transform_homogeneous(vector!(0.0, 0.0, -1.0));
+            let far = projection.transform_homogeneous(vector!(0.0, 0.0, -10.0));
+            assert_approx_eq!(near[2], 0.0, 1e-6);
+            assert_approx_eq!(far[2], 1.0, 1e-6);
+        }
+
+        #[test]
+        fn matrix_transform_f64() {
+            let projection32 = Matrix::<f32, 4, 4>::projection(1.2, 1.5, 0.1, 100.0).unwrap();
+            let projection64 = Matrix::<f64, 4, 4>::projection(1.2, 1.5, 0.1, 100.0).unwrap();
+            let view32 = Matrix::<f32, 4, 4>::view(
+                vector!(1.0, 2.0, 3.0),
+                vector!(0.0, 0.0, 0.0),
+                vector!(0.0, 1.0, 0.0),
+            )
+            .unwrap();
+            let view64 = Matrix::<f64, 4, 4>::view(
+                vector!(1.0, 2.0, 3.0),
+                vector!(0.0, 0.0, 0.0),
+                vector!(0.0, 1.0, 0.0),
+            )
+            .unwrap();
+            let rotation32 =
+                Matrix::<f32, 4, 4>::new_rotation_on_axis(vector!(1.0, 1.0, 0.0), 0.4).unwrap();
+            let rotation64 =
+                Matrix::<f64, 4, 4>::new_rotation_on_axis(vector!(1.0, 1.0, 0.0), 0.4).unwrap();
+            let ortho32 = Matrix::<f32, 4, 4>::ortho(vector!(4.0, 3.0), 0.5, 20.0);
+            let ortho64 = Matrix::<f64, 4, 4>::ortho(vector!(4.0, 3.0), 0.5, 20.0);
+            for (a, b) in [
+                (projection32, projection64),
+                (view32, view64),
+                (rotation32, rotation64),
+                (ortho32, ortho64),
+            ]
+            .iter()
+            {
+                assert_approx_eq!(a.convert::<f64>(), *b, 1e-5);
+            }
+
+            assert_eq!(
+                Matrix::<f64, 4, 4>::projection(std::f64::consts::PI, 1.0, 0.1, 100.0),
+                Err(MatrixError::OutOfRangeFOV)
+            );
+            assert_eq!(
+                Matrix::<f64, 4, 4>::projection(1.0, 1.0, 10.0, 1.0),
+                Err(MatrixError::IncorrectNearFarPlanes)
+            );
+        }
+
+        #[test]
+        fn matrix_projection_config() {
+            let assert_projects =
+                |matrix: &Matrix<f64, 4, 4>, point: Vector<f64, 3>, expected: Vector<f64, 3>| {
+                    assert_vector_approx_eq!(matrix.transform_homogeneous(point), expected, 1e-6);
+                };
+
+            for handedness in [Handedness::LeftHanded, Handedness::RightHanded].iter() {
+                for depth_range in [DepthRange::ZeroToOne, DepthRange::NegativeOneToOne].iter() {
+                    for &reversed_z in [false, true].iter() {
+                        for &infinite_far in [false, true].iter() {
+                            for &flip_y in [false, true].iter() {
+                                let config = ProjectionConfig {
+                                    handedness: *handedness,
+                                    depth_range: *depth_range,
+                                    reversed_z,
+                                    infinite_far,
+                                    flip_y,
+                                };
+                                let low = match depth_range {
+                                    DepthRange::ZeroToOne => 0.0,
+                                    DepthRange::NegativeOneToOne => -1.0,
+                                };
+                                let (near_depth, far_depth) =
+                                    if reversed_z { (1.0, low) } else { (low, 1.0) };
+                                let z = match handedness {
+                                    Handedness::LeftHanded => 1.0,
+                                    Handedness::RightHanded => -1.0,
+                                };
+                                let y = if flip_y { -1.0 } else { 1.0 };
+
+                                // Off-axis frustum from (-1, -2) to (3, 1) on the near plane at 1
+                                let far = if infinite_far { 1e9 } else { 10.0 };
+                                let frustum = Matrix::<f64, 4, 4>::frustum(
+                                    -1.0, 3.0, -2.0, 1.0, 1.0, 10.0, &config,
+                                )
+                                .unwrap();
+                                assert_projects(
+                                    &frustum,
+                                    vector!(-1.0, -2.0, z),
+                                    vector!(-1.0, -y, near_depth),
+                                );
+                                assert_projects(
+                                    &frustum,
+                                    vector!(3.0 * far, 1.0 * far, z * far),
+                                    vector!(1.0, y, far_depth),
+                                );
+                                assert_projects(
+                                    &frustum,
+                                    vector!(1.0, -0.5, z),
+                                    vector!(0.0, 0.0, near_depth),
+                                );
+
+                                // Box from (-1, -2, 1) to (3, 1, 10)
+                                let config = ProjectionConfig {
+                                    infinite_far: false,
+                                    ..config
+                                };
+                                let ortho = Matrix::<f64, 4, 4>::ortho_off_center(
+                                    -1.0, 3.0, -2.0, 1.0, 1.0, 10.0, &config,
+                                )
+                                .unwrap();
+                                assert_projects(
+                                    &ortho,
+                                    vector!(-1.0, -2.0, z),
+                                    vector!(-1.0, -y, near_depth),
+                                );
+                                assert_projects(
+                                    &ortho,
+                                    vector!(3.0, 1.0, z * 10.0),
+                                    vector!(1.0, y, far_depth),
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+
+            // The default configuration matches the original constructors
+            let config = ProjectionConfig::default();
+            let projection = Matrix::<f32, 4, 4>::projection(1.2, 1.5, 0.1, 100.0).unwrap();
+            let configured =
+                Matrix::<f32, 4, 4>::projection_with_config(1.2, 1.5, 0.1, 100.0, &config).unwrap();
+            let ortho = Matrix::<f32, 4, 4>::ortho(vector!(4.0, 2.0), 0.5, 20.0);
+            let ortho_off_center =
+                Matrix::<f32, 4, 4>::ortho_off_center(-2.0, 2.0, -1.0, 1.0, 0.5, 20.0, &config)
+                    .unwrap();
+            assert_approx_eq!(projection, configured, 1e-5);
+            assert_approx_eq!(ortho, ortho_off_center, 1e-5);
+
+            let opengl = ProjectionConfig::OPENGL;
+            assert_eq!(
+                Matrix::<f32, 4, 4>::frustum(1.0, 1.0, -1.0, 1.0, 1.0, 10.0, &opengl),
+                Err(MatrixError::IncorrectFrustumBounds)
+            );
+            assert_eq!(
+                Matrix::<f32, 4, 4>::frustum(-1.0, 1.0, -1.0, 1.0, 0.0, 10.0, &opengl),
+                Err(MatrixError::IncorrectNearFarPlanes)
+            );
+            assert_eq!(
+                Matrix::<f32, 4, 4>::ortho_off_center(-1.0, 1.0, -1.0, 1.0, 1.0, 1.0, &opengl),
+                Err(MatrixError::IncorrectNearFarPlanes)
+            );
+            let infinite = ProjectionConfig {
+                infinite_far: true,
+                ..opengl
+            };
+            assert_eq!(
+                Matrix::<f32, 4, 4>::ortho_off_center(-1.0, 1.0, -1.0, 1.0, 1.0, 10.0, &infinite),
+                Err(MatrixError::IncorrectNearFarPlanes)
+            );
+        }
+
+        #[test]
+        fn matrix_layout_conversions() {
+            // 3 columns and 2 rows:
+            // | 1 2 3 |
+            // | 4 5 6 |
+            let m = Matrix::<i32, 3, 2>::from_rows([vector!(1, 2, 3), vector!(4, 5, 6)]);
+            assert_eq!(m[0], vector!(1, 4));
+            assert_eq!(m.row(1), vector!(4, 5, 6));
+
+            let row_major = [1, 2, 3, 4, 5, 6];
+            let column_major = [1, 4, 2, 5, 3, 6];
+            assert_eq!(m.to_row_major_array(), row_major);
+            assert_eq!(m.to_column_major_array(), column_major);
+            assert_eq!(m.as_slice(), &column_major);
+            assert_eq!(m.as_flat_slice(), &column_major);
+            assert_eq!(Matrix::from_row_major_slice(&row_major).unwrap(), m);
+            assert_eq!(Matrix::from_column_major_slice(&column_major).unwrap(), m);
+            assert_eq!(
+                Matrix::<i32, 3, 2>::from_row_major_slice(&row_major[1..]),
+                Err(MatrixError::IncorrectElementCount)
+            );
+
+            // Round trips, including through the transpose
+            let mut m = Matrix::<f32, 4, 4>::new_scale(vector!(4.0, 5.0, 6.0)).unwrap();
+            m.set_position(vector!(1.0, 2.0, 3.0)).unwrap();
+            let row_major = m.to_row_major_array();
+            assert_eq!(Matrix::from_row_major_slice(&row_major).unwrap(), m);
+            assert_eq!(
+                Matrix::from_column_major_slice(&row_major).unwrap(),
+                m.transposed().unwrap()
+            );
+            assert_eq!(
+                Matrix::from_column_major_slice(&m.to_column_major_array()).unwrap(),
+                m
+            );
+            assert_eq!(&row_major[12..], &[0.0, 0.0, 0.0, 1.0]);
+            assert_eq!(
+                Matrix::from_rows([m.row(0), m.row(1), m.row(2), m.row(3)]),
+                m
+            );
+        }
+
+        #[test]
+        fn matrix_views() {
+            let mut m = Matrix::<i32, 3, 4>::from_array_array(&[
+                [0, 1, 2, 3],
+                [10, 11, 12, 13],
+                [20, 21, 22, 23],
+            ]);
+
+            // Views borrow blocks without copying
+            let view = m.fixed_view::<2, 2>(1, 2).unwrap();
+            assert_eq!(view[0], [12, 13]);
+            assert_eq!(view.column(1), &[22, 23]);
+            assert_eq!(view.row(1), vector!(13, 23));
+            assert_eq!(
+                view.rows().collect::<Vec<_>>(),
+                [vector!(12, 22), vector!(13, 23)]
+            );
+            assert_eq!(
+                view.to_matrix(),
+                Matrix::from_array_array(&[[12, 13], [22, 23]])
+            );
+            assert_eq!(
+                m.fixed_view::<2, 2>(2, 0).unwrap_err(),
+                MatrixError::TooFewColumns
+            );
+            assert_eq!(
+                m.fixed_view::<1, 3>(0, 2).unwrap_err(),
+                MatrixError::TooFewRows
+            );
+            assert_eq!(
+                m.fixed_view::<2, 2>(usize::MAX, 0).unwrap_err(),
+                MatrixError::TooFewColumns
+            );
+            assert_eq!(
+                m.fixed_view_mut::<2, 2>(0, usize::MAX).unwrap_err(),
+                MatrixError::TooFewRows
+            );
+
+            // Empty blocks may sit on the far edge, but not beyond it
+            assert!(m.fixed_view::<0, 0>(3, 1).is_ok());
+            assert!(m.fixed_view_mut::<0, 2>(3, 2).is_ok());
+            assert_eq!(
+                m.fixed_view::<0, 0>(4, 0).unwrap_err(),
+                MatrixError::TooFewColumns
+            );
+
+            let mut view = m.fixed_view_mut::<2, 3>(0, 1).unwrap();
+            view[1][2] = -13;
+            view.set_row(0, vector!(-1, -11));
+            assert_eq!(view.row(2), vector!(3, -13));
+            assert_eq!(m[1], vector!(10, -11, 12, -13));
+            assert_eq!(m[0], vector!(0, -1, 2, 3));
+
+            // Row and column iteration and rearrangement
+            assert_eq!(m.columns().count(), 3);
+            assert_eq!(m.columns().nth(2), Some(&vector!(20, 21, 22, 23)));
+            assert_eq!(m.rows().nth(3), Some(vector!(3, -13, 23)));
+            m.set_row(0, vector!(7, 8, 9));
+            assert_eq!(m.row(0), vector!(7, 8, 9));
+            m.swap_rows(0, 3);
+            assert_eq!(m.row(0), vector!(3, -13, 23));
+            assert_eq!(m.row(3), vector!(7, 8, 9));
+            m.swap_columns(0, 2);
+            assert_eq!(m.row(3), vector!(9, 8, 7));
+
+            let block = Matrix::<i32, 2, 2>::from_array_array(&[[1, 2], [3, 4]]);
+            m.copy_block_from(1, 1, &block).unwrap();
+            assert_eq!(m.fixed_view::<2, 2>(1, 1).unwrap().to_matrix(), block);
+            assert_eq!(
+                m.copy_block_from(2, 0, &block),
+                Err(MatrixError::TooFewColumns)
+            );
+        }
+
+        #[test]
+        fn matrix_diagonal_views() {
+            let mut m = Matrix::<f32, 4, 4>::new_scale(vector!(2.0, 3.0, 4.0)).unwrap();
+            m.set_position(vector!(5.0, 6.0, 7.0)).unwrap();
+            assert_eq!(m.diagonal().to_vector(), vector!(2.0, 3.0, 4.0, 1.0));
+            assert_eq!(m.diagonal()[2], 4.0);
+            assert_eq!(m.diagonal().iter().sum::<f32>(), 10.0);
+
+            let mut diagonal = m.diagonal_mut();
+            diagonal[0] = 1.0;
+            diagonal.copy_from(&vector!(1.0, 1.0, 1.0, 2.0));
+            assert_eq!(m[3], vector!(5.0, 6.0, 7.0, 2.0));
+
+            // The rotation part of a transform is a borrowed 3x3 block
+            let rotation = Matrix::<f32, 3, 3>::from_array_array(&[
                 [0.0, 1.0, 0.0],
-                [0.0, 1.0, 1.0],
-                [1.0, 0.0, 0.0],
-                [1.0, 0.0, 1.0],
-                [1.0, 1.0, 0.0],
-                [1.0, 1.0, 1.0],
-            ];
-            let targets = [[0.0], [0.0], [0.0], [1.0], [0.0], [0.0], [1.0], [1.0]];
+                [-1.0, 0.0, 0.0],
+                [0.0, 0.0, 1.0],
+            ]);
+            m.upper_left_3x3_mut().copy_from(&rotation);
+            assert_eq!(m.upper_left_3x3().to_matrix(), rotation);
+            assert_eq!(m.position().unwrap(), vector!(5.0, 6.0, 7.0));
+            m.upper_left_3x3_mut().fill(0.0);
+            assert_eq!(
+                m.upper_left_3x3().to_matrix(),
+                Matrix::from_array_array(&[[0.0; 3]; 3])
+            );
+            assert_eq!(m[3], vector!(5.0, 6.0, 7.0, 2.0));
+        }
+    }
+
+    mod decomposition {
+        use crate::{assert_approx_eq, assert_vector_approx_eq, vector, Matrix, MatrixError};
+
+        fn diagonal<const N: usize>(values: &[f64]) -> Matrix<f64, N, N> {
+            let mut matrix = Matrix::identity();
+            for (idx, &value) in values.iter().enumerate() {
+                matrix[idx][idx] = value;
+            }
+            matrix
+        }
+
+        // 4 rows and 3 columns
+        fn tall() -> Matrix<f64, 3, 4> {
+            Matrix::from_array_array(&[
+                [2.0, -1.0, 0.5, 3.0],
+                [1.0, 4.0, -2.0, 0.0],
+                [-3.0, 1.0, 1.0, 2.0],
+            ])
+        }
+
+        fn spd() -> Matrix<f64, 3, 3> {
+            Matrix::from_array_array(&[[4.0, 2.0, -1.0], [2.0, 5.0, 1.0], [-1.0, 1.0, 3.0]])
+        }
+
+        #[test]
+        fn decomposition_lu() {
+            let a = Matrix::<f64, 5, 5>::from_array_array(&[
+                [0.0, 2.0, 1.0, -1.0, 3.0],
+                [1.0, 1.0, 0.0, 2.0, 1.0],
+                [4.0, -2.0, 3.0, 0.0, 1.0],
+                [2.0, 0.0, 1.0, 5.0, -1.0],
+                [1.0, 3.0, -2.0, 1.0, 2.0],
+            ]);
+            let lu = a.lu().unwrap();
+            assert_approx_eq!(lu.p() * a, lu.l() * lu.u(), 1e-12);
+            assert_approx_eq!(lu.determinant(), a.determinant(), 1e-9);
+
+            let b = vector!(1.0, -2.0, 0.5, 3.0, 4.0);
+            let x = lu.solve(&b).unwrap();
+            assert_vector_approx_eq!(a * x, b, 1e-12);
+
+            let singular = Matrix::<f64, 2, 2>::from_array_array(&[[1.0, 2.0], [2.0, 4.0]]);
+            assert_eq!(singular.lu().unwrap_err(), MatrixError::Singular);
+        }
 
-            // Train the network
-            for _ in 0..10000 {
-                for (input, targets) in inputs.iter().zip(targets.iter()) {
-                    let _ = nn.forward(&input);
-                    nn.backward(targets);
+        #[test]
+        fn decomposition_qr() {
+            let a = tall();
+            let qr = a.qr();
+            assert_approx_eq!(*qr.q() * *qr.r(), a, 1e-12);
+            assert_approx_eq!(
+                qr.q().transposed().unwrap() * *qr.q(),
+                Matrix::identity(),
+                1e-12
+            );
+            for column_idx in 0..3 {
+                for row_idx in (column_idx + 1)..4 {
+                    assert_eq!(qr.r()[column_idx][row_idx], 0.0);
                 }
             }
 
-            // Test the network
-            println!("{:?}", nn.forward(&[1.0, 1.0, 0.0]).unwrap());
-            println!("{:?}", nn.forward(&[0.0, 0.0, 1.0]).unwrap());
-            println!("{:?}", nn.forward(&[1.0, 0.0, 1.0]).unwrap());
-            println!("{:?}", nn.forward(&[0.0, 1.0, 1.0]).unwrap());
-            println!("{:?}", nn.forward(&[0.0, 0.0, 0.0]).unwrap());
+            // The least squares residual is orthogonal to the columns of A
+            let b = vector!(1.0, 2.0, 3.0, 4.0);
+            let x = qr.solve(&b).unwrap();
+            let residual = a * x - b;
+            for column_idx in 0..3 {
+                assert_approx_eq!(a[column_idx].dot(&residual), 0.0, 1e-12);
+            }
+
+            let wide = Matrix::<f32, 3, 2>::from_array_array(&[[1.0, 2.0], [3.0, 4.0], [5.0, 7.0]]);
+            let qr = wide.qr();
+            assert_approx_eq!(*qr.q() * *qr.r(), wide, 1e-5);
+            assert_eq!(qr.solve(&vector!(1.0, 2.0)), Err(MatrixError::TooFewRows));
+
+            let rank_deficient =
+                Matrix::<f64, 2, 3>::from_array_array(&[[1.0, 2.0, 3.0], [2.0, 4.0, 6.0]]);
+            assert_eq!(
+                rank_deficient.qr().solve(&vector!(1.0, 2.0, 3.0)),
+                Err(MatrixError::Singular)
+            );
+        }
+
+        #[test]
+        fn decomposition_cholesky() {
+            let a = spd();
+            let cholesky = a.cholesky().unwrap();
+            let l = *cholesky.l();
+            assert_approx_eq!(l * l.transposed().unwrap(), a, 1e-12);
+            assert_eq!(l[1][0], 0.0);
+            assert_eq!(l[2][1], 0.0);
+
+            let b = vector!(1.0, 0.0, -2.0);
+            assert_vector_approx_eq!(a * cholesky.solve(&b).unwrap(), b, 1e-12);
+
+            let indefinite = Matrix::<f32, 2, 2>::from_array_array(&[[1.0, 2.0], [2.0, 1.0]]);
+            assert_eq!(
+                indefinite.cholesky().unwrap_err(),
+                MatrixError::NotPositiveDefinite
+            );
+            let asymmetric = Matrix::<f64, 2, 2>::from_array_array(&[[2.0, 1.0], [0.0, 2.0]]);
+            assert_eq!(
+                asymmetric.cholesky().unwrap_err(),
+                MatrixError::NotSymmetric
+            );
+        }
+
+        #[test]
+        fn decomposition_svd() {
+            let a = tall();
+            let svd = a.svd().unwrap();
+            let s = svd.singular_values();
+            assert!(s[0] >= s[1] && s[1] >= s[2] && s[2] > 0.0);
+            assert_approx_eq!(
+                *svd.u() * diagonal(s.as_slice()) * svd.v().transposed().unwrap(),
+                a,
+                1e-12
+            );
+            assert_approx_eq!(
+                svd.v().transposed().unwrap() * *svd.v(),
+                Matrix::identity(),
+                1e-12
+            );
+            assert_eq!(svd.rank(), 3);
+
+            // Full column rank, so the solution matches the QR least squares one
+            let b = vector!(1.0, 2.0, 3.0, 4.0);
+            assert_vector_approx_eq!(svd.solve(&b).unwrap(), a.qr().solve(&b).unwrap(), 1e-12);
+
+            let rank_deficient = Matrix::<f32, 3, 3>::from_array_array(&[
+                [1.0, 2.0, 3.0],
+                [2.0, 4.0, 6.0],
+                [0.0, 1.0, 1.0],
+            ]);
+            let svd = rank_deficient.svd().unwrap();
+            assert_eq!(svd.rank(), 2);
+            let mut s = Matrix::<f32, 3, 3>::identity();
+            for idx in 0..3 {
+                s[idx][idx] = svd.singular_values()[idx];
+            }
+            assert_approx_eq!(
+                *svd.u() * s * svd.v().transposed().unwrap(),
+                rank_deficient,
+                1e-5
+            );
+        }
+
+        #[test]
+        fn decomposition_symmetric_eigen() {
+            let a = spd();
+            let eigen = a.symmetric_eigen().unwrap();
+            let values = eigen.eigenvalues();
+            let vectors = *eigen.eigenvectors();
+            assert!(values[0] >= values[1] && values[1] >= values[2]);
+            for idx in 0..3 {
+                assert_vector_approx_eq!(a * vectors[idx], vectors[idx] * values[idx], 1e-12);
+            }
+            assert_approx_eq!(
+                vectors * diagonal(values.as_slice()) * vectors.transposed().unwrap(),
+                a,
+                1e-12
+            );
+            assert_approx_eq!(values.sum(), 12.0, 1e-12);
+
+            let b = vector!(2.0, -1.0, 1.0);
+            assert_vector_approx_eq!(a * eigen.solve(&b).unwrap(), b, 1e-12);
+
+            let a = Matrix::<f32, 4, 4>::from_array_array(&[
+                [2.0, 1.0, 0.0, 0.5],
+                [1.0, -3.0, 1.0, 0.0],
+                [0.0, 1.0, 1.0, 2.0],
+                [0.5, 0.0, 2.0, 0.0],
+            ]);
+            let eigen = a.symmetric_eigen().unwrap();
+            for idx in 0..4 {
+                let vector = eigen.eigenvectors()[idx];
+                assert_vector_approx_eq!(a * vector, vector * eigen.eigenvalues()[idx], 1e-5);
+            }
+            assert_eq!(
+                Matrix::<f64, 2, 2>::from_array_array(&[[1.0, 0.0], [1.0, 1.0]])
+                    .symmetric_eigen()
+                    .unwrap_err(),
+                MatrixError::NotSymmetric
+            );
         }
 
         #[test]
-        fn nnet_train() {}
-    }*/
+        fn decomposition_solve() {
+            let a = spd();
+            let b = vector!(3.0, -1.0, 2.0);
+            let x = a.solve(&b).unwrap();
+            assert_vector_approx_eq!(a * x, b, 1e-12);
+            assert_vector_approx_eq!(x, a.inverse().unwrap() * b, 1e-12);
+
+            let a = Matrix::<f64, 6, 6>::from_array_array(&[
+                [4.0, 1.0, 0.0, 2.0, -1.0, 0.5],
+                [1.0, 5.0, 1.0, 0.0, 0.0, 1.0],
+                [0.0, -2.0, 6.0, 1.0, 1.0, 0.0],
+                [3.0, 0.0, 1.0, 7.0, 0.0, 2.0],
+                [0.0, 1.0, -1.0, 0.0, 3.0, 1.0],
+                [1.0, 0.0, 2.0, -1.0, 1.0, 8.0],
+            ]);
+            let b = vector!(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
+            assert_vector_approx_eq!(a * a.solve(&b).unwrap(), b, 1e-12);
+
+            // Several right-hand sides at once
+            let rhs = Matrix::<f64, 2, 6>::from_array_array(&[
+                [1.0, 0.0, 0.0, 0.0, 0.0, 1.0],
+                [0.0, -1.0, 2.0, 0.0, 3.0, 0.0],
+            ]);
+            let x = a.solve_matrix(&rhs).unwrap();
+            assert_approx_eq!(a * x, rhs, 1e-12);
+            assert_vector_approx_eq!(x[1], a.solve(&rhs[1]).unwrap(), 1e-12);
+
+            // Condition numbers
+            let scaled = diagonal::<2>(&[1.0, 100.0]);
+            assert_approx_eq!(scaled.lu().unwrap().condition_number(), 100.0, 1e-12);
+            assert_approx_eq!(scaled.condition_number().unwrap(), 100.0, 1e-12);
+            assert_approx_eq!(
+                Matrix::<f32, 3, 3>::identity().condition_number().unwrap(),
+                1.0,
+                1e-6
+            );
+
+            let nearly_singular =
+                Matrix::<f64, 2, 2>::from_array_array(&[[1.0, 2.0], [2.0, 4.000000000000001]]);
+            assert_eq!(
+                nearly_singular.solve(&vector!(1.0, 2.0)),
+                Err(MatrixError::IllConditioned)
+            );
+            assert!(nearly_singular.lu().unwrap().condition_number() > 1e15);
+            let singular = Matrix::<f64, 2, 2>::from_array_array(&[[1.0, 2.0], [2.0, 4.0]]);
+            assert_eq!(
+                singular.solve(&vector!(1.0, 2.0)),
+                Err(MatrixError::Singular)
+            );
+        }
+
+        #[test]
+        fn decomposition_least_squares() {
+            // Fit y = 2x + 1 through points lying exactly on the line
+            let a = Matrix::<f64, 2, 5>::from_array_array(&[
+                [0.0, 1.0, 2.0, 3.0, 4.0],
+                [1.0, 1.0, 1.0, 1.0, 1.0],
+            ]);
+            let b = vector!(1.0, 3.0, 5.0, 7.0, 9.0);
+            assert_vector_approx_eq!(a.least_squares(&b).unwrap(), vector!(2.0, 1.0), 1e-12);
+
+            // With noise the normal equations are satisfied instead
+            let b = vector!(1.1, 2.9, 5.2, 6.8, 9.1);
+            let x = a.least_squares(&b).unwrap();
+            let residual = a * x - b;
+            assert_approx_eq!(a[0].dot(&residual), 0.0, 1e-12);
+            assert_approx_eq!(a[1].dot(&residual), 0.0, 1e-12);
+
+            // Q is orthogonal, so R has the same 2-norm condition number as A
+            let a = tall();
+            assert_approx_eq!(
+                a.qr().condition_number().unwrap(),
+                a.condition_number().unwrap(),
+                1e-9
+            );
+            assert_eq!(
+                Matrix::<f64, 3, 2>::identity().least_squares(&vector!(1.0, 2.0)),
+                Err(MatrixError::TooFewRows)
+            );
+            let dependent =
+                Matrix::<f64, 2, 3>::from_array_array(&[[1.0, 2.0, 3.0], [2.0, 4.0, 6.0]]);
+            assert_eq!(
+                dependent.least_squares(&vector!(1.0, 2.0, 3.0)),
+                Err(MatrixError::IllConditioned)
+            );
+        }
+    }
+
+    mod dynamic {
+        use crate::{
+            assert_approx_eq, assert_vector_approx_eq, vector, DMatrix, DVector, Matrix,
+            MatrixError, MatrixStorage, Vector, VectorError, VectorStorage,
+        };
+        use std::convert::TryFrom;
+
+        fn fixed() -> Matrix<f64, 3, 3> {
+            Matrix::from_array_array(&[[2.0, 1.0, -1.0], [0.0, 3.0, 2.0], [1.0, -2.0, 4.0]])
+        }
+
+        // Written once for both kinds of storage
+        fn trace<T: Copy + std::iter::Sum, M: MatrixStorage<T>>(matrix: &M) -> T {
+            (0..matrix.column_count().min(matrix.row_count()))
+                .map(|idx| *matrix.element(idx, idx))
+                .sum()
+        }
+
+        fn scale<V: VectorStorage<f64>>(vector: &mut V, factor: f64) {
+            for component in vector.as_mut_slice() {
+                *component *= factor;
+            }
+        }
+
+        #[test]
+        fn dynamic_vector() {
+            let a = DVector::new(vec![1.0, 2.0, 3.0]);
+            let b = DVector::from(vector!(4.0, 5.0, 6.0));
+            assert_eq!(a.len(), 3);
+            assert_eq!((&a + &b).unwrap(), DVector::new(vec![5.0, 7.0, 9.0]));
+            assert_eq!((&b - &a).unwrap(), DVector::new(vec![3.0; 3]));
+            assert_eq!((&a * &b).unwrap(), DVector::new(vec![4.0, 10.0, 18.0]));
+            assert_eq!(&a * 2.0, DVector::new(vec![2.0, 4.0, 6.0]));
+            assert_eq!(-&a, DVector::new(vec![-1.0, -2.0, -3.0]));
+            assert_eq!(a.dot(&b), Ok(32.0));
+            assert_eq!(a.length2(), Ok(14.0));
+            assert_approx_eq!(a.length().unwrap(), 14.0f64.sqrt());
+            assert_approx_eq!(a.normalized().unwrap().length().unwrap(), 1.0);
+            assert_eq!(
+                DVector::<f64>::new(vec![]).length(),
+                Err(VectorError::ZeroComponents)
+            );
+            assert_eq!(
+                DVector::<f64>::zero(2).normalized(),
+                Err(VectorError::ZeroLength)
+            );
+
+            let mut c = a.clone();
+            c += 1.0;
+            assert_eq!(c, DVector::new(vec![2.0, 3.0, 4.0]));
+            assert_eq!(c.iter().sum::<f64>(), 9.0);
+
+            // Mismatched lengths are reported instead of panicking
+            let short = DVector::new(vec![1.0, 2.0]);
+            assert_eq!(&a + &short, Err(VectorError::DimensionMismatch));
+            assert_eq!(a.dot(&short), Err(VectorError::DimensionMismatch));
+            assert_eq!(c.add_vector(&short), Err(VectorError::DimensionMismatch));
+
+            // Conversions to fixed-size vectors only succeed for the right length
+            assert_eq!(Vector::<f64, 3>::try_from(b), Ok(vector!(4.0, 5.0, 6.0)));
+            assert_eq!(
+                Vector::<f64, 3>::try_from(short),
+                Err(VectorError::DimensionMismatch)
+            );
+        }
+
+        #[test]
+        fn dynamic_matrix() {
+            let a = DMatrix::from(fixed());
+            assert_eq!(a.column_count(), 3);
+            assert_eq!(a[1][2], 2.0);
+            assert_eq!(a.row(0), DVector::new(vec![2.0, 0.0, 1.0]));
+
+            let b = DMatrix::new(2, 3, vec![1.0, 0.0, 2.0, -1.0, 3.0, 1.0]).unwrap();
+            let product = (&a * &b).unwrap();
+            assert_eq!(product.column_count(), 2);
+            assert_eq!(product.row_count(), 3);
+            let fixed_b = Matrix::<f64, 2, 3>::try_from(b.clone()).unwrap();
+            assert_eq!(product, DMatrix::from(fixed() * fixed_b));
+            assert_eq!(&b * &a, Err(MatrixError::DimensionMismatch));
+            assert_eq!(&a + &b, Err(MatrixError::DimensionMismatch));
+
+            let v = DVector::new(vec![1.0, -1.0, 2.0]);
+            assert_eq!(
+                (&a * &v).unwrap(),
+                DVector::from(fixed() * vector!(1.0, -1.0, 2.0))
+            );
+            assert_eq!(
+                &a * &DVector::new(vec![1.0]),
+                Err(MatrixError::DimensionMismatch)
+            );
+
+            let transposed = b.transposed();
+            assert_eq!(transposed.column_count(), 3);
+            assert_eq!(transposed[2][1], b[1][2]);
+
+            // Square matrix operations agree with the fixed-size implementations
+            assert_approx_eq!(a.determinant().unwrap(), fixed().determinant(), 1e-12);
+            assert_approx_eq!(
+                a.inverse().unwrap(),
+                DMatrix::from(fixed().inverse().unwrap()),
+                1e-12
+            );
+            let x = a.solve(&v).unwrap();
+            assert_vector_approx_eq!(
+                Vector::<f64, 3>::try_from(x).unwrap(),
+                fixed().solve(&vector!(1.0, -1.0, 2.0)).unwrap(),
+                1e-12
+            );
+            assert_eq!(b.determinant(), Err(MatrixError::NotSquare));
+
+            // NaN is refused rather than propagated into the solution
+            let nan = Matrix::<f64, 2, 2>::from_array_array(&[[1.0, f64::NAN], [0.0, 1.0]]);
+            assert_eq!(
+                nan.solve(&vector!(1.0, 2.0)),
+                Err(MatrixError::IllConditioned)
+            );
+            assert_eq!(
+                DMatrix::from(nan).solve(&DVector::new(vec![1.0, 2.0])),
+                Err(MatrixError::IllConditioned)
+            );
+
+            // Both types refuse the same ill-conditioned system
+            let ill = [[1.0, 2.0], [2.0, 4.000000000000001]];
+            assert_eq!(
+                Matrix::<f64, 2, 2>::from_array_array(&ill).solve(&vector!(1.0, 2.0)),
+                Err(MatrixError::IllConditioned)
+            );
+            assert_eq!(
+                DMatrix::from(Matrix::<f64, 2, 2>::from_array_array(&ill))
+                    .solve(&DVector::new(vec![1.0, 2.0])),
+                Err(MatrixError::IllConditioned)
+            );
+            assert_eq!(
+                DMatrix::<f64>::zero(2, 2).inverse(),
+                Err(MatrixError::Singular)
+            );
+
+            assert_eq!(
+                DMatrix::new(2, 2, vec![1.0; 3]),
+                Err(MatrixError::IncorrectElementCount)
+            );
+            assert_eq!(
+                DMatrix::from_columns(&[DVector::new(vec![1.0]), DVector::new(vec![1.0, 2.0])]),
+                Err(MatrixError::DimensionMismatch)
+            );
+            assert_eq!(
+                Matrix::<f64, 3, 2>::try_from(a.clone()),
+                Err(MatrixError::DimensionMismatch)
+            );
+            assert_eq!(
+                DMatrix::<f64>::identity(3),
+                DMatrix::from(Matrix::<f64, 3, 3>::identity())
+            );
+        }
+
+        #[test]
+        fn dynamic_storage() {
+            let m = fixed();
+            assert_eq!(trace(&m), 9.0);
+            assert_eq!(trace(&DMatrix::from(m)), 9.0);
+            assert_eq!(
+                trace(&DMatrix::new(3, 2, vec![1, 2, 3, 4, 5, 6]).unwrap()),
+                5
+            );
+
+            let mut v = vector!(1.0, 2.0);
+            let mut d = DVector::new(vec![1.0, 2.0]);
+            scale(&mut v, 3.0);
+            scale(&mut d, 3.0);
+            assert_eq!(v.as_slice(), d.as_slice());
+            assert_eq!(VectorStorage::len(&v), 2);
+        }
+    }
+
+    mod quaternion {
+        use crate::{
+            assert_approx_eq, assert_vector_approx_eq, vector, EulerOrder, Matrix, MatrixError,
+            Quaternion, QuaternionError, Rand, TransformMatrix, Vector, Zero,
+        };
+        use std::f32::consts::PI;
+
+        const ORDERS: [EulerOrder; 6] = [
+            EulerOrder::XYZ,
+            EulerOrder::XZY,
+            EulerOrder::YXZ,
+            EulerOrder::YZX,
+            EulerOrder::ZXY,
+            EulerOrder::ZYX,
+        ];
+
+        fn random_vector(rand: &mut Rand<f32>) -> Vector<f32, 3> {
+            vector!(
+                rand.next() * 2.0 - 1.0,
+                rand.next() * 2.0 - 1.0,
+                rand.next() * 2.0 - 1.0
+            )
+        }
+
+        fn random_quaternion(rand: &mut Rand<f32>) -> Quaternion {
+            let axis = random_vector(rand) + vector!(0.0, 0.0, 0.01);
+            Quaternion::from_axis_angle(axis, (rand.next() * 2.0 - 1.0) * PI).unwrap()
+        }
+
+        fn assert_same_rotation(a: &Quaternion, b: &Quaternion) {
+            assert!(a.rotation_abs_diff_eq(b, 1e-3), "{:?} != {:?}", a, b);
+        }
+
+        #[test]
+        fn quaternion_rotate_vector() {
+            let mut rand = Rand::<f32>::new(3);
+            for _ in 0..100 {
+                let q = random_quaternion(&mut rand);
+                let v = random_vector(&mut rand);
+                let matrix = Matrix::<f32, 4, 4>::new_rotation(&q).unwrap();
+                assert_vector_approx_eq!(q.rotate_vector(v), matrix.transform_vector(v), 1e-3);
+            }
+
+            let q = Quaternion::from_axis_angle(vector!(0.0, 0.0, 1.0), PI * 0.5).unwrap();
+            assert_vector_approx_eq!(
+                q.rotate_vector(vector!(1.0, 0.0, 0.0)),
+                vector!(0.0, 1.0, 0.0),
+                1e-3
+            );
+
+            // Non-unit quaternions are normalized instead of skewing the matrix
+            let scaled = Quaternion::from_vec(q.xyz() * 3.0, q.w() * 3.0);
+            let v = vector!(1.0, 2.0, 3.0);
+            let matrix = Matrix::<f32, 4, 4>::new_rotation(&scaled).unwrap();
+            assert_vector_approx_eq!(matrix.transform_vector(v), q.rotate_vector(v), 1e-5);
+            let matrix =
+                Matrix::<f32, 4, 4>::from_trs(Vector::zero(), scaled, vector!(1.0, 1.0, 1.0));
+            assert_vector_approx_eq!(matrix.transform_vector(v), q.rotate_vector(v), 1e-5);
+            assert_eq!(
+                Matrix::<f32, 4, 4>::new_rotation(&Quaternion::new(0.0, 0.0, 0.0, 0.0)),
+                Err(MatrixError::QuaternionError(QuaternionError::ZeroLength))
+            );
+        }
+
+        #[test]
+        fn quaternion_f64() {
+            let q = Quaternion::<f64>::from_axis_angle(
+                vector!(0.0, 0.0, 1.0),
+                std::f64::consts::FRAC_PI_2,
+            )
+            .unwrap();
+            let rotated = q.rotate_vector(vector!(1.0, 0.0, 0.0));
+            assert_vector_approx_eq!(rotated, vector!(0.0, 1.0, 0.0), 1e-12);
+
+            let v = vector!(0.3, -2.0, 5.0);
+            let matrix3: Matrix<f64, 3, 3> = q.into();
+            assert_vector_approx_eq!(matrix3 * v, q.rotate_vector(v), 1e-12);
+            let matrix4: Matrix<f64, 4, 4> = q.into();
+            assert_vector_approx_eq!(matrix4.transform_vector(v), q.rotate_vector(v), 1e-12);
+
+            let q32 = Quaternion::from_axis_angle(vector!(1.0, 2.0, 3.0), 0.7).unwrap();
+            let q64: Quaternion<f64> = q32.convert();
+            assert_approx_eq!(q64.length(), 1.0, 1e-6);
+            let matrix = Matrix::<f32, 4, 4>::from(q32);
+            let expected = Matrix::<f32, 4, 4>::new_rotation(&q32).unwrap();
+            let v = vector!(0.3, -2.0, 5.0);
+            assert_vector_approx_eq!(
+                matrix.transform_vector(v),
+                expected.transform_vector(v),
+                1e-3
+            );
+        }
+
+        #[test]
+        fn quaternion_decompose() {
+            let mut rand = Rand::<f32>::new(5);
+            for _ in 0..100 {
+                let translation = random_vector(&mut rand) * 10.0;
+                let rotation = random_quaternion(&mut rand);
+                let scale = random_vector(&mut rand) + vector!(1.5, 1.5, 1.5);
+                let matrix = Matrix::<f32, 4, 4>::from_trs(translation, rotation, scale);
+                let expected = Matrix::<f32, 4, 4>::new_position(translation).unwrap()
+                    * Matrix::<f32, 4, 4>::from(rotation)
+                    * Matrix::<f32, 4, 4>::new_scale(scale).unwrap();
+                let v = random_vector(&mut rand);
+                assert_vector_approx_eq!(
+                    matrix.transform_point(v),
+                    expected.transform_point(v),
+                    1e-3
+                );
+
+                let (t, r, s) = matrix.decompose().unwrap();
+                assert_vector_approx_eq!(t, translation, 1e-3);
+                assert_same_rotation(&r, &rotation);
+                assert_vector_approx_eq!(s, scale, 1e-3);
+            }
+
+            // Rotations close to half a turn
+            for angle in [PI - 0.0001, PI, -PI + 0.0001].iter() {
+                let q = Quaternion::from_axis_angle(vector!(1.0, 1.0, 0.0), *angle).unwrap();
+                let back = Quaternion::from_rotation_matrix(&q.into());
+                assert_same_rotation(&q, &back);
+            }
+
+            let mirrored = Matrix::<f32, 4, 4>::new_scale(vector!(1.0, -1.0, 1.0)).unwrap();
+            assert_eq!(mirrored.decompose(), Err(MatrixError::NegativeScale));
+            let flat = Matrix::<f32, 4, 4>::from_trs(
+                vector!(0.0, 0.0, 0.0),
+                Quaternion::IDENTITY,
+                vector!(1.0, 0.0, 1.0),
+            );
+            assert_eq!(flat.decompose(), Err(MatrixError::Singular));
+
+            // The tolerance is relative, so uniformly tiny scales still decompose
+            let rotation = Quaternion::from_axis_angle(vector!(0.0, 1.0, 0.0), 0.5).unwrap();
+            let tiny = Matrix::<f32, 4, 4>::from_trs(
+                vector!(1.0, 2.0, 3.0),
+                rotation,
+                vector!(1e-6, 2e-6, 1e-6),
+            );
+            let (_, r, s) = tiny.decompose().unwrap();
+            assert_same_rotation(&rotation, &r);
+            assert_vector_approx_eq!(s, vector!(1e-6, 2e-6, 1e-6), 1e-12);
+            let q = Quaternion::new(0.0, 1e-4, 0.0, 0.0);
+            assert_eq!(q.normalized().unwrap(), Quaternion::new(0.0, 1.0, 0.0, 0.0));
+        }
+
+        #[test]
+        fn quaternion_euler() {
+            let mut rand = Rand::<f32>::new(7);
+            let axes = [
+                vector!(1.0, 0.0, 0.0),
+                vector!(0.0, 1.0, 0.0),
+                vector!(0.0, 0.0, 1.0),
+            ];
+            for order in ORDERS.iter() {
+                let [i, j, k] = order.axes();
+                for _ in 0..50 {
+                    let angles = random_vector(&mut rand) * PI;
+                    let q = Quaternion::from_euler(angles, *order);
+
+                    // Matches applying the matrices in order
+                    let rotation = |axis: usize| {
+                        Matrix::<f32, 4, 4>::new_rotation_on_axis(axes[axis], angles[axis]).unwrap()
+                    };
+                    let matrix = rotation(k) * rotation(j) * rotation(i);
+                    let v = random_vector(&mut rand);
+                    assert_vector_approx_eq!(q.rotate_vector(v), matrix.transform_vector(v), 1e-3);
+
+                    // Round trips through Euler angles
+                    let back = Quaternion::from_euler(q.to_euler(*order), *order);
+                    assert_same_rotation(&q, &back);
+                }
+
+                // Gimbal lock
+                let mut angles = vector!(0.3, 0.3, 0.3);
+                angles[j] = PI * 0.5;
+                let q = Quaternion::from_euler(angles, *order);
+                let back = Quaternion::from_euler(q.to_euler(*order), *order);
+                assert_same_rotation(&q, &back);
+            }
+        }
+
+        #[test]
+        fn quaternion_interpolation() {
+            let a = Quaternion::IDENTITY;
+            let b = Quaternion::from_axis_angle(vector!(0.0, 1.0, 0.0), PI * 0.5).unwrap();
+            assert_same_rotation(&a.slerp(&b, 0.0), &a);
+            assert_same_rotation(&a.slerp(&b, 1.0), &b);
+            let half = Quaternion::from_axis_angle(vector!(0.0, 1.0, 0.0), PI * 0.25).unwrap();
+            assert_same_rotation(&a.slerp(&b, 0.5), &half);
+            assert_same_rotation(&a.nlerp(&b, 0.5), &half);
+            assert_approx_eq!(a.angle_between(&b), PI * 0.5, 1e-5);
+
+            // Takes the shortest path even if the other quaternion is negated
+            assert_same_rotation(&a.slerp(&-b, 0.5), &half);
+            assert_approx_eq!(a.slerp(&-b, 0.5).length(), 1.0, 1e-5);
+        }
+
+        #[test]
+        fn quaternion_rotation_arc() {
+            let mut rand = Rand::<f32>::new(11);
+            for _ in 0..100 {
+                let from = random_vector(&mut rand);
+                let to = random_vector(&mut rand);
+                let q = Quaternion::from_rotation_arc(from, to).unwrap();
+                assert_vector_approx_eq!(
+                    q.rotate_vector(from.normalized().unwrap()),
+                    to.normalized().unwrap(),
+                    1e-3
+                );
+            }
+
+            let from = vector!(0.0, 0.0, 1.0);
+            let q = Quaternion::from_rotation_arc(from, -from).unwrap();
+            assert_vector_approx_eq!(q.rotate_vector(from), -from, 1e-3);
+            assert!(Quaternion::from_rotation_arc(from, vector!(0.0, 0.0, 0.0)).is_err());
+        }
+
+        #[test]
+        fn quaternion_look_rotation() {
+            let forward = vector!(1.0, 0.0, -1.0);
+            let up = vector!(0.0, 1.0, 0.0);
+            let q = Quaternion::look_rotation(forward, up).unwrap();
+            assert_vector_approx_eq!(
+                q.rotate_vector(vector!(0.0, 0.0, -1.0)),
+                forward.normalized().unwrap(),
+                1e-3
+            );
+            assert_vector_approx_eq!(q.rotate_vector(up), up, 1e-3);
+            assert!(Quaternion::look_rotation(up, up).is_err());
+        }
+    }
+
+    mod transform {
+        use crate::{
+            assert_approx_eq, assert_vector_approx_eq, vector, Matrix, Quaternion, Transform,
+            TransformMatrix,
+        };
+
+        fn transforms() -> (Transform, Transform) {
+            let a = Transform::new(
+                vector!(1.0, 2.0, 3.0),
+                Quaternion::from_axis_angle(vector!(0.0, 1.0, 1.0), 0.8).unwrap(),
+                vector!(2.0, 2.0, 2.0),
+            );
+            let b = Transform::new(
+                vector!(-1.0, 0.0, 4.0),
+                Quaternion::from_axis_angle(vector!(1.0, -2.0, 0.5), -1.3).unwrap(),
+                vector!(1.0, 2.0, 3.0),
+            );
+            (a, b)
+        }
+
+        #[test]
+        fn transform_compose() {
+            let (a, b) = transforms();
+            let p = vector!(0.5, -1.0, 2.0);
+            assert_vector_approx_eq!(
+                (a * b).transform_point(p),
+                a.transform_point(b.transform_point(p)),
+                1e-4
+            );
+            assert_vector_approx_eq!(
+                (a * b).transform_vector(p),
+                a.transform_vector(b.transform_vector(p)),
+                1e-4
+            );
+
+            let matrix = Matrix::from(a) * Matrix::from(b);
+            assert_vector_approx_eq!(matrix.transform_point(p), (a * b).transform_point(p), 1e-4);
+        }
+
+        #[test]
+        fn transform_inverse() {
+            let (a, _) = transforms();
+            let p = vector!(0.5, -1.0, 2.0);
+            assert_vector_approx_eq!(a.inverse().transform_point(a.transform_point(p)), p, 1e-4);
+            assert_vector_approx_eq!((a.inverse() * a).transform_point(p), p, 1e-4);
+        }
+
+        #[test]
+        fn transform_lerp() {
+            let (a, b) = transforms();
+            assert_eq!(a.lerp(&b, 0.0).translation, a.translation);
+            assert_vector_approx_eq!(a.lerp(&b, 1.0).translation, b.translation, 1e-4);
+            assert_vector_approx_eq!(a.lerp(&b, 0.5).translation, vector!(0.0, 1.0, 3.5), 1e-4);
+            assert_vector_approx_eq!(a.lerp(&b, 0.5).scale, vector!(1.5, 2.0, 2.5), 1e-4);
+            let p = vector!(0.5, -1.0, 2.0);
+            assert_vector_approx_eq!(
+                a.lerp(&b, 1.0).rotation.rotate_vector(p),
+                b.rotation.rotate_vector(p),
+                1e-4
+            );
+        }
+
+        #[test]
+        fn transform_matrix() {
+            let (a, b) = transforms();
+            let p = vector!(0.5, -1.0, 2.0);
+            let rotation = Matrix::<f32, 4, 4>::new_rotation(&b.rotation).unwrap();
+            let matrix = Matrix::from(Transform::from_rotation(b.rotation));
+            assert_vector_approx_eq!(matrix.transform_point(p), rotation.transform_point(p), 1e-4);
+
+            let back = Transform::from_matrix(&Matrix::from(b)).unwrap();
+            assert_vector_approx_eq!(back.transform_point(p), b.transform_point(p), 1e-4);
+            assert_vector_approx_eq!(
+                Matrix::from(a).transform_point(p),
+                a.transform_point(p),
+                1e-4
+            );
+
+            // The matrix is the TransformMatrix rotation composed with translation and scale
+            let composed = Matrix::new_position(b.translation).unwrap()
+                * Matrix::new_rotation(&b.rotation).unwrap()
+                * Matrix::new_scale(b.scale).unwrap();
+            assert_approx_eq!(Matrix::from(b), composed, 1e-6);
+
+            // Tiny rotations survive the conversion
+            let tiny = Transform::from_rotation(
+                Quaternion::from_axis_angle(vector!(0.0, 0.0, 1.0), 1e-6).unwrap(),
+            );
+            assert!(Matrix::from(tiny)[0][1] > 0.0);
+        }
+    }
+
+    mod traits {
+        use crate::{
+            assert_approx_eq, assert_vector_approx_eq, vector, ApproxEq, DVector, Float, Matrix,
+            Num, Quaternion, Real, Signed, Vector,
+        };
+
+        fn sum_of_squares<T: Num + Copy>(values: &[T]) -> T {
+            values.iter().map(|&value| value * value).sum()
+        }
+
+        fn unit_length<T: Real>(vector: Vector<T, 3>) -> bool {
+            let length: T = vector.normalized().unwrap().length().unwrap();
+            (length - T::one()).abs() < T::from(1e-5)
+        }
+
+        #[test]
+        fn traits_num() {
+            assert_eq!(sum_of_squares(&[1u8, 2, 3]), 14);
+            assert_eq!(sum_of_squares(&[-1isize, 2, -3]), 14);
+            assert_eq!(sum_of_squares(&[0.5f64, 1.5]), 2.5);
+        }
+
+        #[test]
+        fn traits_signed() {
+            assert_eq!(Signed::signum(&-3i32), -1);
+            assert_eq!(Signed::signum(&0i64), 0);
+            assert_eq!(Signed::signum(&2.5f32), 1.0);
+        }
+
+        #[test]
+        fn traits_real() {
+            assert!(unit_length(vector!(1.0f32, 2.0, 3.0)));
+            assert!(unit_length(vector!(-4.0f64, 0.5, 7.0)));
+            assert_eq!(Real::clamp(&5.0f32, &-1.0, &1.0), 1.0);
+            assert_eq!(Real::clamp(&-5.0f64, &-1.0, &1.0), -1.0);
+            assert_eq!(Real::fract(&2.75f64), 0.75);
+            assert_eq!(Real::floor(&-0.5f32), -1.0);
+            assert_approx_eq!(Real::ln(&Real::exp(&2.0f64)), 2.0, 1e-12);
+        }
+
+        #[test]
+        fn traits_float() {
+            assert!(<f32 as Float>::nan().is_nan());
+            assert!(<f64 as Float>::infinity().is_infinite());
+            assert!(!<f64 as Float>::neg_infinity().is_finite());
+            assert!(Float::is_normal(&1.0f32));
+            assert!(!Float::is_normal(&0.0f64));
+        }
+
+        #[test]
+        fn traits_approx_eq() {
+            assert!(1.0f32.abs_diff_eq(&1.05, 0.1));
+            assert!(!1.0f32.abs_diff_eq(&1.2, 0.1));
+            assert!(f64::INFINITY.abs_diff_eq(&f64::INFINITY, 0.0));
+            assert!(!f64::INFINITY.abs_diff_eq(&f64::NEG_INFINITY, 1.0));
+            assert!(1e6f64.relative_eq(&(1e6 + 0.5), 1e-9, 1e-6));
+            assert!(!1e-6f64.relative_eq(&2e-6, 1e-9, 1e-6));
+            assert!(1.0f32.ulps_eq(&(1.0 + f32::EPSILON), 0.0, 1));
+            assert!(!1.0f32.ulps_eq(&(1.0 + 4.0 * f32::EPSILON), 0.0, 3));
+            assert!(!(-0.0f64).ulps_eq(&1e-300, 0.0, 4));
+            assert!(!f32::NAN.ulps_eq(&f32::NAN, 0.0, 4));
+            assert!(!f32::from_bits(0x7f80_0001).ulps_eq(&f32::INFINITY, 0.0, 1));
+            assert!((0.1f64 + 0.2).approx_eq(&0.3));
+            assert!(!f32::nan().approx_eq(&f32::nan()));
+
+            assert!(vector!(0.1f64 + 0.2, 1.0).approx_eq(&vector!(0.3, 1.0)));
+            assert!(!vector!(1.0f32, 2.0).abs_diff_eq(&vector!(1.0, 2.1), 0.05));
+            let matrix = Matrix::<f64, 2, 2>::from_array_array(&[[1.0, 2.0], [3.0, 4.0]]);
+            assert_approx_eq!(matrix * matrix.inverse().unwrap(), Matrix::identity());
+
+            let q = Quaternion::from_axis_angle(vector!(1.0, 2.0, 3.0), 0.5).unwrap();
+            assert!(!q.abs_diff_eq(&-q, 1e-6));
+            assert!(q.rotation_abs_diff_eq(&-q, 1e-6));
+            assert!(!q.rotation_abs_diff_eq(&Quaternion::IDENTITY, 1e-6));
+        }
+
+        #[test]
+        #[should_panic(expected = "lengths 2 and 3")]
+        fn approx_eq_length_mismatch() {
+            assert_vector_approx_eq!(
+                DVector::new(vec![1.0, 2.0]),
+                DVector::new(vec![1.0, 2.0, 3.0]),
+                1e-6
+            );
+        }
+    }
+    mod pod {
+        use crate::{
+            bytes_of, cast_slice, cast_slice_mut, vector, Matrix, MatrixError, PodCastError,
+            Quaternion, Vector, VectorError, Zeroable,
+        };
+        use std::mem::{align_of, size_of};
+
+        #[test]
+        fn pod_layout() {
+            assert_eq!(size_of::<Vector<f32, 2>>(), 8);
+            assert_eq!(size_of::<Vector<f32, 3>>(), 12);
+            assert_eq!(size_of::<Vector<f32, 4>>(), 16);
+            assert_eq!(size_of::<Vector<f64, 4>>(), 32);
+            assert_eq!(size_of::<Vector<i32, 3>>(), 12);
+            assert_eq!(size_of::<Vector<u8, 4>>(), 4);
+            assert_eq!(align_of::<Vector<f32, 3>>(), align_of::<f32>());
+            assert_eq!(align_of::<Vector<f64, 2>>(), align_of::<f64>());
+            assert_eq!(size_of::<Matrix<f32, 3, 3>>(), 36);
+            assert_eq!(size_of::<Matrix<f32, 4, 4>>(), 64);
+            assert_eq!(size_of::<Matrix<f64, 4, 4>>(), 128);
+            assert_eq!(size_of::<Matrix<f32, 2, 4>>(), 32);
+            assert_eq!(align_of::<Matrix<f32, 4, 4>>(), align_of::<f32>());
+            assert_eq!(align_of::<Matrix<f64, 3, 3>>(), align_of::<f64>());
+            assert_eq!(size_of::<Quaternion>(), 16);
+            assert_eq!(size_of::<Quaternion<f64>>(), 32);
+            assert_eq!(align_of::<Quaternion>(), align_of::<f32>());
+        }
+
+        #[test]
+        fn pod_bytes() {
+            let v = vector!(1u16, 0x0203);
+            assert_eq!(v.as_bytes(), bytes_of(&[1u16, 0x0203]));
+            assert_eq!(v.as_slice(), &[1, 0x0203]);
+            assert_eq!(unsafe { *v.as_ptr().add(1) }, 0x0203);
+            assert_eq!(Vector::<u16, 2>::from_slice(&[1, 0x0203]).unwrap(), v);
+            assert_eq!(
+                Vector::<u16, 2>::from_slice(&[1]),
+                Err(VectorError::IncorrectComponentCount)
+            );
+
+            let m = Matrix::<f32, 4, 4>::new_position(vector!(1.0, 2.0, 3.0)).unwrap();
+            assert_eq!(m.as_bytes().len(), 64);
+            assert_eq!(&m.as_slice()[12..], &[1.0, 2.0, 3.0, 1.0]);
+            assert_eq!(Matrix::from_slice(m.as_slice()).unwrap(), m);
+            assert_eq!(
+                Matrix::<f32, 4, 4>::from_slice(&[0.0; 15]),
+                Err(MatrixError::IncorrectElementCount)
+            );
+            assert_eq!(
+                Matrix::<f32, 4, 4>::zeroed(),
+                Matrix::from_slice(&[0.0; 16]).unwrap()
+            );
+        }
+
+        #[test]
+        fn pod_cast_slice() {
+            let matrices = [Matrix::<f32, 4, 4>::identity(); 3];
+            let bytes: &[u8] = cast_slice(&matrices).unwrap();
+            assert_eq!(bytes.len(), 3 * 64);
+            assert_eq!(bytes, matrices[0].as_bytes().repeat(3).as_slice());
+
+            let floats: &[f32] = cast_slice(&matrices).unwrap();
+            assert_eq!(floats.len(), 48);
+            let columns: &[Vector<f32, 4>] = cast_slice(floats).unwrap();
+            assert_eq!(columns[5], vector!(0.0, 1.0, 0.0, 0.0));
+            assert_eq!(
+                cast_slice::<f32, Vector<f32, 3>>(&floats[..4]),
+                Err(PodCastError::SizeMismatch)
+            );
+
+            let mut positions = [vector!(0.0f32, 0.0, 0.0); 2];
+            cast_slice_mut::<_, f32>(&mut positions).unwrap()[4] = 5.0;
+            assert_eq!(positions[1], vector!(0.0, 5.0, 0.0));
+
+            let words = [0u32; 4];
+            let bytes: &[u8] = cast_slice(&words).unwrap();
+            assert_eq!(
+                cast_slice::<u8, u32>(&bytes[1..5]),
+                Err(PodCastError::AlignmentMismatch)
+            );
+        }
+    }
+
+    mod std_layout {
+        use crate::{std_layout_struct, vector, AsStd140, AsStd430, Matrix, Vector};
+        use std::convert::TryInto;
+
+        std_layout_struct! {
+            struct Inner {
+                d: i32,
+                e: Vector<u32, 2>,
+            }
+        }
+
+        std_layout_struct! {
+            struct Element {
+                j: Vector<u32, 3>,
+                k: Vector<f32, 2>,
+                l: [f32; 2],
+                m: Vector<f32, 2>,
+                n: [Matrix<f32, 3, 3>; 2],
+            }
+        }
+
+        // The example block from the ARB_uniform_buffer_object specification
+        std_layout_struct! {
+            struct Example {
+                a: f32,
+                b: Vector<f32, 2>,
+                c: Vector<f32, 3>,
+                f: Inner,
+                g: f32,
+                h: [f32; 2],
+                i: Matrix<f32, 2, 3>,
+                o: [Element; 2],
+            }
+        }
+
+        fn example() -> Example {
+            let element = |base: f32| Element {
+                j: vector!(base as u32 + 1, base as u32 + 2, base as u32 + 3),
+                k: vector!(base + 4.0, base + 5.0),
+                l: [base + 6.0, base + 7.0],
+                m: vector!(base + 8.0, base + 9.0),
+                n: [
+                    Matrix::from_slice(&[10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0, 17.0, 18.0])
+                        .unwrap(),
+                    Matrix::identity(),
+                ],
+            };
+            Example {
+                a: 1.0,
+                b: vector!(2.0, 3.0),
+                c: vector!(4.0, 5.0, 6.0),
+                f: Inner {
+                    d: -7,
+                    e: vector!(8, 9),
+                },
+                g: 10.0,
+                h: [11.0, 12.0],
+                i: Matrix::from_slice(&[13.0, 14.0, 15.0, 16.0, 17.0, 18.0]).unwrap(),
+                o: [element(100.0), element(200.0)],
+            }
+        }
+
+        fn read_f32(bytes: &[u8], offset: usize) -> f32 {
+            f32::from_ne_bytes(bytes[offset..offset + 4].try_into().unwrap())
+        }
+
+        fn read_u32(bytes: &[u8], offset: usize) -> u32 {
+            u32::from_ne_bytes(bytes[offset..offset + 4].try_into().unwrap())
+        }
+
+        #[test]
+        fn std_layout_sizes() {
+            assert_eq!(<Vector<f32, 2> as AsStd140>::STD140_ALIGN, 8);
+            assert_eq!(<Vector<f32, 3> as AsStd140>::STD140_ALIGN, 16);
+            assert_eq!(<Vector<f32, 3> as AsStd140>::STD140_SIZE, 12);
+            assert_eq!(<Vector<f64, 3> as AsStd430>::STD430_ALIGN, 32);
+            assert_eq!(<[f32; 4] as AsStd140>::STD140_SIZE, 64);
+            assert_eq!(<[f32; 4] as AsStd430>::STD430_SIZE, 16);
+            assert_eq!(<[Vector<f32, 3>; 2] as AsStd430>::STD430_SIZE, 32);
+            assert_eq!(<Matrix<f32, 2, 2> as AsStd140>::STD140_SIZE, 32);
+            assert_eq!(<Matrix<f32, 2, 2> as AsStd430>::STD430_SIZE, 16);
+            assert_eq!(<Matrix<f32, 3, 3> as AsStd140>::STD140_SIZE, 48);
+            assert_eq!(<Matrix<f32, 3, 3> as AsStd430>::STD430_SIZE, 48);
+            assert_eq!(<Matrix<f32, 4, 4> as AsStd140>::STD140_SIZE, 64);
+            assert_eq!(<Matrix<f64, 4, 4> as AsStd140>::STD140_ALIGN, 32);
+
+            let m = Matrix::<f32, 3, 3>::identity();
+            let bytes = m.as_std140();
+            assert_eq!(read_f32(&bytes, 0), 1.0);
+            assert_eq!(read_f32(&bytes, 12), 0.0);
+            assert_eq!(read_f32(&bytes, 20), 1.0);
+            assert_eq!(read_f32(&bytes, 40), 1.0);
+            assert_eq!(
+                vector!(1.0f32, 2.0).as_std430(),
+                vector!(1.0f32, 2.0).as_bytes()
+            );
+        }
+
+        #[test]
+        fn std_layout_std140() {
+            assert_eq!(<Inner as AsStd140>::STD140_ALIGN, 16);
+            assert_eq!(<Inner as AsStd140>::STD140_SIZE, 16);
+            assert_eq!(<Element as AsStd140>::STD140_SIZE, 176);
+            assert_eq!(<Example as AsStd140>::STD140_SIZE, 480);
+
+            let bytes = example().as_std140();
+            assert_eq!(bytes.len(), 480);
+            let floats = [
+                (0, 1.0),
+                (8, 2.0),
+                (12, 3.0),
+                (16, 4.0),
+                (24, 6.0),
+                (48, 10.0),
+                (64, 11.0),
+                (80, 12.0),
+                (96, 13.0),
+                (104, 15.0),
+                (112, 16.0),
+                (120, 18.0),
+                (144, 104.0),
+                (160, 106.0),
+                (176, 107.0),
+                (192, 108.0),
+                (208, 10.0),
+                (224, 13.0),
+                (240, 16.0),
+                (248, 18.0),
+                (256, 1.0),
+                (276, 1.0),
+                (296, 1.0),
+                (320, 204.0),
+                (336, 206.0),
+                (352, 207.0),
+                (368, 208.0),
+                (384, 10.0),
+                (432, 1.0),
+            ];
+            for &(offset, value) in floats.iter() {
+                assert_eq!(read_f32(&bytes, offset), value, "offset {}", offset);
+            }
+            assert_eq!(read_u32(&bytes, 32), -7i32 as u32);
+            assert_eq!(read_u32(&bytes, 40), 8);
+            assert_eq!(read_u32(&bytes, 44), 9);
+            assert_eq!(read_u32(&bytes, 128), 101);
+            assert_eq!(read_u32(&bytes, 136), 103);
+            assert_eq!(read_u32(&bytes, 304), 201);
+        }
+
+        #[test]
+        fn std_layout_std430() {
+            assert_eq!(<Inner as AsStd430>::STD430_ALIGN, 8);
+            assert_eq!(<Inner as AsStd430>::STD430_SIZE, 16);
+            assert_eq!(<Element as AsStd430>::STD430_ALIGN, 16);
+            assert_eq!(<Element as AsStd430>::STD430_SIZE, 144);
+            assert_eq!(<Example as AsStd430>::STD430_SIZE, 384);
+
+            let bytes = example().as_std430();
+            assert_eq!(bytes.len(), 384);
+            let floats = [
+                (0, 1.0),
+                (8, 2.0),
+                (16, 4.0),
+                (24, 6.0),
+                (48, 10.0),
+                (52, 11.0),
+                (56, 12.0),
+                (64, 13.0),
+                (80, 16.0),
+                (88, 18.0),
+                (112, 104.0),
+                (116, 105.0),
+                (120, 106.0),
+                (124, 107.0),
+                (128, 108.0),
+                (144, 10.0),
+                (160, 13.0),
+                (176, 16.0),
+                (192, 1.0),
+                (256, 204.0),
+                (264, 206.0),
+                (268, 207.0),
+                (272, 208.0),
+                (288, 10.0),
+            ];
+            for &(offset, value) in floats.iter() {
+                assert_eq!(read_f32(&bytes, offset), value, "offset {}", offset);
+            }
+            assert_eq!(read_u32(&bytes, 32), -7i32 as u32);
+            assert_eq!(read_u32(&bytes, 40), 8);
+            assert_eq!(read_u32(&bytes, 96), 101);
+            assert_eq!(read_u32(&bytes, 104), 103);
+            assert_eq!(read_u32(&bytes, 240), 201);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    mod serialization {
+        use crate::{
+            assert_approx_eq, assert_vector_approx_eq, vector, Activation, DMatrix, DVector,
+            Gaussian2, Gaussian3, Matrix, MatrixError, NNet, Quaternion, QuaternionError, Rand,
+            ValRand, Vector, VectorError,
+        };
+
+        fn round_trip<T>(value: &T) -> T
+        where
+            T: serde::Serialize + serde::de::DeserializeOwned,
+        {
+            serde_json::from_str(&serde_json::to_string(value).unwrap()).unwrap()
+        }
+
+        #[test]
+        fn serialization_algebra() {
+            let v = vector!(1.5f32, -2.0, 3.0);
+            assert_eq!(serde_json::to_string(&v).unwrap(), "[1.5,-2.0,3.0]");
+            assert_eq!(round_trip(&v), v);
+
+            let m = Matrix::<i32, 3, 2>::from_array_array(&[[1, 2], [3, 4], [5, 6]]);
+            assert_eq!(serde_json::to_string(&m).unwrap(), "[[1,2],[3,4],[5,6]]");
+            assert_eq!(round_trip(&m), m);
+
+            let q = Quaternion::<f64>::new(0.0, 0.5, 0.0, 1.0);
+            assert_eq!(serde_json::to_string(&q).unwrap(), "[0.0,0.5,0.0,1.0]");
+            assert_eq!(round_trip(&q), q);
+
+            let error = MatrixError::VectorError(VectorError::ZeroLength);
+            assert_eq!(round_trip(&error), error);
+            assert_eq!(
+                round_trip(&QuaternionError::ZeroLength),
+                QuaternionError::ZeroLength
+            );
+        }
+
+        #[test]
+        fn serialization_lengths() {
+            assert!(serde_json::from_str::<Vector<f32, 3>>("[1.0,2.0]").is_err());
+            assert!(serde_json::from_str::<Vector<f32, 3>>("[1.0,2.0,3.0,4.0]").is_err());
+            assert!(serde_json::from_str::<Vector<f32, 3>>("{}").is_err());
+            assert!(serde_json::from_str::<Matrix<i32, 2, 2>>("[[1,2],[3]]").is_err());
+            assert!(serde_json::from_str::<Matrix<i32, 2, 2>>("[[1,2]]").is_err());
+            assert!(serde_json::from_str::<Quaternion>("[0.0,0.0,1.0]").is_err());
+        }
+
+        #[test]
+        fn serialization_rand() {
+            let mut rand = Rand::<f32>::new(42);
+            rand.next();
+            let mut restored = round_trip(&rand);
+            for _ in 0..10 {
+                assert_eq!(rand.next(), restored.next());
+            }
+
+            // A pending second value has to survive the checkpoint as well
+            let mut gaussian = Gaussian2::<f64>::new(7);
+            gaussian.next();
+            let mut restored = round_trip(&gaussian);
+            for _ in 0..10 {
+                assert_eq!(gaussian.next(), restored.next());
+            }
+
+            let mut gaussian = Gaussian3::<f32>::new(9);
+            gaussian.next();
+            let mut restored = round_trip(&gaussian);
+            for _ in 0..10 {
+                assert_eq!(gaussian.next(), restored.next());
+            }
+
+            let val_rand = ValRand::new(5u64);
+            let restored = round_trip(&val_rand);
+            assert_eq!(val_rand.next::<f32, _>(3u64), restored.next::<f32, _>(3u64));
+        }
+
+        #[test]
+        fn serialization_dynamic() {
+            let v = DVector::new(vec![1.0, 2.5]);
+            assert_eq!(serde_json::to_string(&v).unwrap(), "[1.0,2.5]");
+            assert_eq!(round_trip(&v), v);
+
+            let m = DMatrix::from(Matrix::<f64, 2, 3>::identity());
+            assert_eq!(
+                serde_json::to_string(&m).unwrap(),
+                serde_json::to_string(&Matrix::<f64, 2, 3>::identity()).unwrap()
+            );
+            assert_eq!(round_trip(&m), m);
+            assert!(serde_json::from_str::<DMatrix<f64>>("[[1.0],[1.0,2.0]]").is_err());
+        }
+
+        #[test]
+        fn serialization_nnet() {
+            let mut nn: NNet<2, 1, 2, 3> = NNet::new(&mut 11, 0.25).unwrap();
+            nn.set_output_activation(Activation::Tanh);
+            let mut restored = round_trip(&nn);
+            for (restored, weight) in restored.weights().iter().zip(nn.weights().iter()) {
+                assert_approx_eq!(*restored, *weight, 1e-12);
+            }
+            assert_eq!(restored.learning_rate(), 0.25);
+            assert_eq!(restored.output_activation(), Activation::Tanh);
+            assert_vector_approx_eq!(
+                restored.forward(&[0.5, -1.0]).unwrap(),
+                nn.forward(&[0.5, -1.0]).unwrap(),
+                1e-12
+            );
+
+            let json = serde_json::to_string(&nn).unwrap();
+            assert!(serde_json::from_str::<NNet<2, 1, 2, 4>>(&json).is_err());
+        }
+    }
+
+    mod nnet {
+        use crate::{Activation, NNet, NNetError, VectorError};
+
+        const INPUTS: [[f64; 3]; 8] = [
+            [0.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0],
+            [0.0, 1.0, 0.0],
+            [0.0, 1.0, 1.0],
+            [1.0, 0.0, 0.0],
+            [1.0, 0.0, 1.0],
+            [1.0, 1.0, 0.0],
+            [1.0, 1.0, 1.0],
+        ];
+        const TARGETS: [[f64; 1]; 8] = [[0.0], [0.0], [0.0], [1.0], [0.0], [0.0], [1.0], [1.0]];
+
+        fn train<const HIDDEN_LAYERS: usize, const HIDDEN_SIZE: usize>(
+            nn: &mut NNet<3, 1, HIDDEN_LAYERS, HIDDEN_SIZE>,
+            epochs: usize,
+        ) -> f64 {
+            let mut loss = 0.0;
+            for _ in 0..epochs {
+                loss = 0.0;
+                for (input, targets) in INPUTS.iter().zip(TARGETS.iter()) {
+                    nn.forward(input).unwrap();
+                    loss += nn.backward(targets).unwrap();
+                }
+            }
+            loss
+        }
+
+        fn assert_learned<const HIDDEN_LAYERS: usize, const HIDDEN_SIZE: usize>(
+            nn: &mut NNet<3, 1, HIDDEN_LAYERS, HIDDEN_SIZE>,
+        ) {
+            for (input, targets) in INPUTS.iter().zip(TARGETS.iter()) {
+                let output = nn.forward(input).unwrap()[0];
+                assert!(
+                    (output - targets[0]).abs() < 0.2,
+                    "{:?} -> {}, expected {}",
+                    input,
+                    output,
+                    targets[0]
+                );
+            }
+        }
+
+        #[test]
+        fn nnet_new() {
+            // Create network
+            let mut seed = 13473; // Seed for random weights
+            let mut nn: NNet<3, 1, 2, 2> = NNet::new(&mut seed, 0.5).unwrap();
+
+            // Train the network until the truth table is reproduced
+            let first_loss = train(&mut nn, 1);
+            let loss = train(&mut nn, 10000);
+            assert!(loss < first_loss * 0.1, "{} -> {}", first_loss, loss);
+            assert_learned(&mut nn);
+
+            assert_eq!(
+                NNet::<3, 1, 0, 2>::new(&mut seed, 0.5).unwrap_err(),
+                NNetError::NoHiddenLayers
+            );
+            assert_eq!(
+                NNet::<3, 1, 1, 0>::new(&mut seed, 0.5).unwrap_err(),
+                NNetError::ZeroHiddenSize
+            );
+        }
+
+        #[test]
+        fn nnet_train() {
+            let mut seed = 2841;
+            let mut nn: NNet<3, 1, 1, 4> = NNet::new(&mut seed, 0.1).unwrap();
+            nn.set_hidden_activation(Activation::Tanh);
+            train(&mut nn, 5000);
+            assert_learned(&mut nn);
+
+            let mut nn: NNet<3, 1, 1, 8> = NNet::new(&mut seed, 0.05).unwrap();
+            nn.set_hidden_activation(Activation::Relu);
+            train(&mut nn, 5000);
+            assert_learned(&mut nn);
+
+            // Weights round trip through their flattened form
+            let weights = nn.weights();
+            assert_eq!(weights.len(), nn.weight_count());
+            assert_eq!(weights.len(), 4 * 8 + 9);
+            let mut restored = NNet::<3, 1, 1, 8>::from_weights(&weights, 0.05).unwrap();
+            restored.set_hidden_activation(Activation::Relu);
+            assert_eq!(restored.backward(&[1.0]), Err(NNetError::NoForwardPass));
+            assert_eq!(restored.weights(), weights);
+            assert_eq!(
+                restored.forward(&INPUTS[3]).unwrap(),
+                nn.forward(&INPUTS[3]).unwrap()
+            );
+
+            assert_eq!(
+                restored.set_weights(&weights[1..]),
+                Err(NNetError::IncorrectWeightCount)
+            );
+            assert_eq!(
+                restored.forward(&[1.0, 0.0]),
+                Err(NNetError::VectorError(VectorError::IncorrectComponentCount))
+            );
+        }
+    }
 }
@@ -16,3 +16,45 @@ impl Abs for f64 {
         f64::abs(*self)
     }
 }
+
+impl Abs for i8 {
+    type Output = Self;
+    fn abs(&self) -> Self::Output {
+        i8::abs(*self)
+    }
+}
+
+impl Abs for i16 {
+    type Output = Self;
+    fn abs(&self) -> Self::Output {
+        i16::abs(*self)
+    }
+}
+
+impl Abs for i32 {
+    type Output = Self;
+    fn abs(&self) -> Self::Output {
+        i32::abs(*self)
+    }
+}
+
+impl Abs for i64 {
+    type Output = Self;
+    fn abs(&self) -> Self::Output {
+        i64::abs(*self)
+    }
+}
+
+impl Abs for i128 {
+    type Output = Self;
+    fn abs(&self) -> Self::Output {
+        i128::abs(*self)
+    }
+}
+
+impl Abs for isize {
+    type Output = Self;
+    fn abs(&self) -> Self::Output {
+        isize::abs(*self)
+    }
+}
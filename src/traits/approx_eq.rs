@@ -0,0 +1,85 @@
+pub trait ApproxEq {
+    type Epsilon: Copy;
+
+    fn default_epsilon() -> Self::Epsilon;
+    fn default_max_relative() -> Self::Epsilon;
+    fn default_max_ulps() -> u32 {
+        4
+    }
+
+    // The difference is at most epsilon
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool;
+
+    // The difference is at most epsilon, or at most max_relative times the larger magnitude
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool;
+
+    // The difference is at most epsilon, or the values are at most max_ulps representable
+    // values apart
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool;
+
+    fn approx_eq(&self, other: &Self) -> bool {
+        self.relative_eq(other, Self::default_epsilon(), Self::default_max_relative())
+    }
+}
+
+macro_rules! approx_eq_float_impl {
+    ($t:ident) => {
+        impl ApproxEq for $t {
+            type Epsilon = $t;
+
+            fn default_epsilon() -> $t {
+                $t::EPSILON
+            }
+
+            fn default_max_relative() -> $t {
+                $t::EPSILON
+            }
+
+            fn abs_diff_eq(&self, other: &$t, epsilon: $t) -> bool {
+                // Equal infinities have a NaN difference
+                if self == other {
+                    return true;
+                }
+                (self - other).abs() <= epsilon
+            }
+
+            fn relative_eq(&self, other: &$t, epsilon: $t, max_relative: $t) -> bool {
+                if self == other {
+                    return true;
+                }
+                if self.is_infinite() || other.is_infinite() {
+                    return false;
+                }
+                let difference = (self - other).abs();
+                if difference <= epsilon {
+                    return true;
+                }
+                difference <= self.abs().max(other.abs()) * max_relative
+            }
+
+            fn ulps_eq(&self, other: &$t, epsilon: $t, max_ulps: u32) -> bool {
+                // NaN bit patterns can be close to each other or to infinity
+                if self.is_nan() || other.is_nan() {
+                    return false;
+                }
+                if self.abs_diff_eq(other, epsilon) {
+                    return true;
+                }
+                if self.is_sign_positive() != other.is_sign_positive() {
+                    return false;
+                }
+                let (a, b) = (self.to_bits(), other.to_bits());
+                let ulps = if a > b { a - b } else { b - a };
+                ulps <= max_ulps.into()
+            }
+        }
+    };
+}
+
+approx_eq_float_impl!(f32);
+approx_eq_float_impl!(f64);
@@ -0,0 +1,71 @@
+use crate::Real;
+
+pub trait Float: Real {
+    fn nan() -> Self;
+    fn infinity() -> Self;
+    fn neg_infinity() -> Self;
+    fn is_nan(&self) -> bool;
+    fn is_infinite(&self) -> bool;
+    fn is_finite(&self) -> bool;
+    fn is_normal(&self) -> bool;
+}
+
+impl Float for f32 {
+    fn nan() -> Self {
+        f32::NAN
+    }
+
+    fn infinity() -> Self {
+        f32::INFINITY
+    }
+
+    fn neg_infinity() -> Self {
+        f32::NEG_INFINITY
+    }
+
+    fn is_nan(&self) -> bool {
+        f32::is_nan(*self)
+    }
+
+    fn is_infinite(&self) -> bool {
+        f32::is_infinite(*self)
+    }
+
+    fn is_finite(&self) -> bool {
+        f32::is_finite(*self)
+    }
+
+    fn is_normal(&self) -> bool {
+        f32::is_normal(*self)
+    }
+}
+
+impl Float for f64 {
+    fn nan() -> Self {
+        f64::NAN
+    }
+
+    fn infinity() -> Self {
+        f64::INFINITY
+    }
+
+    fn neg_infinity() -> Self {
+        f64::NEG_INFINITY
+    }
+
+    fn is_nan(&self) -> bool {
+        f64::is_nan(*self)
+    }
+
+    fn is_infinite(&self) -> bool {
+        f64::is_infinite(*self)
+    }
+
+    fn is_finite(&self) -> bool {
+        f64::is_finite(*self)
+    }
+
+    fn is_normal(&self) -> bool {
+        f64::is_normal(*self)
+    }
+}
@@ -19,6 +19,33 @@ pub use two::*;
 mod abs;
 pub use abs::*;
 
+mod trig;
+pub use trig::*;
+
+mod num;
+pub use num::*;
+
+mod signed;
+pub use signed::*;
+
+mod real;
+pub use real::*;
+
+mod float;
+pub use float::*;
+
+mod approx_eq;
+pub use approx_eq::*;
+
+mod pod;
+pub use pod::*;
+
+mod std_layout;
+pub use std_layout::*;
+
+mod storage;
+pub use storage::*;
+
 mod dot;
 pub use dot::*;
 
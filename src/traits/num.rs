@@ -0,0 +1,45 @@
+use crate::{One, Zero};
+use std::iter::Sum;
+use std::ops::{Add, Div, Mul, Rem, Sub};
+
+pub trait Num:
+    Add<Self, Output = Self>
+    + Sub<Self, Output = Self>
+    + Mul<Self, Output = Self>
+    + Div<Self, Output = Self>
+    + Rem<Self, Output = Self>
+    + Zero
+    + One
+    + Sum
+    + PartialEq
+    + Clone
+{
+}
+
+impl Num for i8 {}
+
+impl Num for i16 {}
+
+impl Num for i32 {}
+
+impl Num for i64 {}
+
+impl Num for i128 {}
+
+impl Num for isize {}
+
+impl Num for u8 {}
+
+impl Num for u16 {}
+
+impl Num for u32 {}
+
+impl Num for u64 {}
+
+impl Num for u128 {}
+
+impl Num for usize {}
+
+impl Num for f32 {}
+
+impl Num for f64 {}
@@ -73,3 +73,15 @@ impl One for u128 {
         1
     }
 }
+
+impl One for isize {
+    fn one() -> Self {
+        1
+    }
+}
+
+impl One for usize {
+    fn one() -> Self {
+        1
+    }
+}
@@ -0,0 +1,116 @@
+use std::mem::{align_of, size_of};
+use std::slice;
+
+/// # Safety
+/// The all-zero bit pattern must be a valid value of the type
+pub unsafe trait Zeroable: Sized {
+    fn zeroed() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+
+/// # Safety
+/// The type must have no padding, no invalid bit patterns and no pointers, so that it can be
+/// freely reinterpreted as and built from bytes. Structs need `#[repr(C)]` or
+/// `#[repr(transparent)]`
+pub unsafe trait Pod: Zeroable + Copy + 'static {}
+
+unsafe impl Zeroable for i8 {}
+unsafe impl Pod for i8 {}
+
+unsafe impl Zeroable for i16 {}
+unsafe impl Pod for i16 {}
+
+unsafe impl Zeroable for i32 {}
+unsafe impl Pod for i32 {}
+
+unsafe impl Zeroable for i64 {}
+unsafe impl Pod for i64 {}
+
+unsafe impl Zeroable for i128 {}
+unsafe impl Pod for i128 {}
+
+unsafe impl Zeroable for isize {}
+unsafe impl Pod for isize {}
+
+unsafe impl Zeroable for u8 {}
+unsafe impl Pod for u8 {}
+
+unsafe impl Zeroable for u16 {}
+unsafe impl Pod for u16 {}
+
+unsafe impl Zeroable for u32 {}
+unsafe impl Pod for u32 {}
+
+unsafe impl Zeroable for u64 {}
+unsafe impl Pod for u64 {}
+
+unsafe impl Zeroable for u128 {}
+unsafe impl Pod for u128 {}
+
+unsafe impl Zeroable for usize {}
+unsafe impl Pod for usize {}
+
+unsafe impl Zeroable for f32 {}
+unsafe impl Pod for f32 {}
+
+unsafe impl Zeroable for f64 {}
+unsafe impl Pod for f64 {}
+
+unsafe impl<T: Zeroable, const N: usize> Zeroable for [T; N] {}
+unsafe impl<T: Pod, const N: usize> Pod for [T; N] {}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PodCastError {
+    SizeMismatch,
+    AlignmentMismatch,
+}
+
+pub fn bytes_of<T: Pod>(value: &T) -> &[u8] {
+    unsafe { slice::from_raw_parts(value as *const T as *const u8, size_of::<T>()) }
+}
+
+pub fn bytes_of_mut<T: Pod>(value: &mut T) -> &mut [u8] {
+    unsafe { slice::from_raw_parts_mut(value as *mut T as *mut u8, size_of::<T>()) }
+}
+
+// Reinterprets a slice as another Pod type. The byte length has to be a multiple of the target
+// size, and the data has to be aligned for the target type
+pub fn cast_slice<A: Pod, B: Pod>(values: &[A]) -> Result<&[B], PodCastError> {
+    let (ptr, bytes) = cast_slice_parts::<A, B>(values.as_ptr() as usize, values.len())?;
+    Ok(unsafe { slice::from_raw_parts(ptr as *const B, bytes) })
+}
+
+pub fn cast_slice_mut<A: Pod, B: Pod>(values: &mut [A]) -> Result<&mut [B], PodCastError> {
+    let (ptr, bytes) = cast_slice_parts::<A, B>(values.as_mut_ptr() as usize, values.len())?;
+    Ok(unsafe { slice::from_raw_parts_mut(ptr as *mut B, bytes) })
+}
+
+fn cast_slice_parts<A, B>(address: usize, len: usize) -> Result<(usize, usize), PodCastError> {
+    let bytes = len * size_of::<A>();
+    if size_of::<B>() == 0 {
+        return if bytes == 0 {
+            Ok((address, 0))
+        } else {
+            Err(PodCastError::SizeMismatch)
+        };
+    }
+    if bytes / size_of::<B>() * size_of::<B>() != bytes {
+        return Err(PodCastError::SizeMismatch);
+    }
+    // Alignments are always powers of two
+    if address & (align_of::<B>() - 1) != 0 {
+        return Err(PodCastError::AlignmentMismatch);
+    }
+    Ok((address, bytes / size_of::<B>()))
+}
+
+// Fails to compile unless the type has the given size and alignment
+#[macro_export]
+macro_rules! assert_layout {
+    ($t:ty, size: $size:expr, align: $align:expr) => {
+        const _: [(); $size] = [(); std::mem::size_of::<$t>()];
+        const _: [(); $align] = [(); std::mem::align_of::<$t>()];
+    };
+}
@@ -0,0 +1,134 @@
+use crate::{Signed, Sqrt, Trig, Two};
+
+pub trait Real: Signed + Two + Sqrt<Output = Self> + Trig + From<f32> + Copy {
+    fn epsilon() -> Self;
+    fn pi() -> Self;
+    fn exp(&self) -> Self;
+    fn ln(&self) -> Self;
+    fn powf(&self, exponent: &Self) -> Self;
+    fn floor(&self) -> Self;
+    fn ceil(&self) -> Self;
+    fn round(&self) -> Self;
+    fn trunc(&self) -> Self;
+    fn fract(&self) -> Self;
+    fn recip(&self) -> Self;
+    fn min(&self, other: &Self) -> Self;
+    fn max(&self, other: &Self) -> Self;
+    fn clamp(&self, min: &Self, max: &Self) -> Self {
+        self.max(min).min(max)
+    }
+}
+
+impl Real for f32 {
+    fn epsilon() -> Self {
+        f32::EPSILON
+    }
+
+    fn pi() -> Self {
+        std::f32::consts::PI
+    }
+
+    fn exp(&self) -> Self {
+        f32::exp(*self)
+    }
+
+    fn ln(&self) -> Self {
+        f32::ln(*self)
+    }
+
+    fn powf(&self, exponent: &Self) -> Self {
+        f32::powf(*self, *exponent)
+    }
+
+    fn floor(&self) -> Self {
+        f32::floor(*self)
+    }
+
+    fn ceil(&self) -> Self {
+        f32::ceil(*self)
+    }
+
+    fn round(&self) -> Self {
+        f32::round(*self)
+    }
+
+    fn trunc(&self) -> Self {
+        f32::trunc(*self)
+    }
+
+    fn fract(&self) -> Self {
+        f32::fract(*self)
+    }
+
+    fn recip(&self) -> Self {
+        f32::recip(*self)
+    }
+
+    fn min(&self, other: &Self) -> Self {
+        f32::min(*self, *other)
+    }
+
+    fn max(&self, other: &Self) -> Self {
+        f32::max(*self, *other)
+    }
+}
+
+impl Real for f64 {
+    fn epsilon() -> Self {
+        f64::EPSILON
+    }
+
+    fn pi() -> Self {
+        std::f64::consts::PI
+    }
+
+    fn exp(&self) -> Self {
+        f64::exp(*self)
+    }
+
+    fn ln(&self) -> Self {
+        f64::ln(*self)
+    }
+
+    fn powf(&self, exponent: &Self) -> Self {
+        f64::powf(*self, *exponent)
+    }
+
+    fn floor(&self) -> Self {
+        f64::floor(*self)
+    }
+
+    fn ceil(&self) -> Self {
+        f64::ceil(*self)
+    }
+
+    fn round(&self) -> Self {
+        f64::round(*self)
+    }
+
+    fn trunc(&self) -> Self {
+        f64::trunc(*self)
+    }
+
+    fn fract(&self) -> Self {
+        f64::fract(*self)
+    }
+
+    fn recip(&self) -> Self {
+        f64::recip(*self)
+    }
+
+    fn min(&self, other: &Self) -> Self {
+        f64::min(*self, *other)
+    }
+
+    fn max(&self, other: &Self) -> Self {
+        f64::max(*self, *other)
+    }
+}
+
+// Lengths at or below this count as zero. It scales with the largest length involved, so the
+// same check works for tiny and huge inputs alike
+pub(crate) fn zero_tolerance<T: Real>(largest: T) -> T {
+    T::epsilon() * largest
+}
@@ -0,0 +1,54 @@
+use crate::{Abs, Num};
+use std::ops::Neg;
+
+pub trait Signed: Num + Neg<Output = Self> + Abs<Output = Self> + PartialOrd {
+    fn signum(&self) -> Self;
+}
+
+impl Signed for i8 {
+    fn signum(&self) -> Self {
+        i8::signum(*self)
+    }
+}
+
+impl Signed for i16 {
+    fn signum(&self) -> Self {
+        i16::signum(*self)
+    }
+}
+
+impl Signed for i32 {
+    fn signum(&self) -> Self {
+        i32::signum(*self)
+    }
+}
+
+impl Signed for i64 {
+    fn signum(&self) -> Self {
+        i64::signum(*self)
+    }
+}
+
+impl Signed for i128 {
+    fn signum(&self) -> Self {
+        i128::signum(*self)
+    }
+}
+
+impl Signed for isize {
+    fn signum(&self) -> Self {
+        isize::signum(*self)
+    }
+}
+
+impl Signed for f32 {
+    fn signum(&self) -> Self {
+        f32::signum(*self)
+    }
+}
+
+impl Signed for f64 {
+    fn signum(&self) -> Self {
+        f64::signum(*self)
+    }
+}
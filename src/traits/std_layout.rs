@@ -0,0 +1,200 @@
+use crate::{bytes_of, Matrix, Pod, Vector};
+use std::mem::size_of;
+
+// GLSL uniform (std140) and storage (std430) buffer layouts. Both align scalars and vectors the
+// same way (vec3 takes the alignment of vec4), but std140 additionally rounds the alignment of
+// array elements, matrix columns and structs up to that of a vec4
+
+pub trait AsStd140 {
+    const STD140_ALIGN: usize;
+    const STD140_SIZE: usize;
+
+    // Writes the padded representation to the start of bytes, which must hold STD140_SIZE bytes
+    fn write_std140(&self, bytes: &mut [u8]);
+
+    fn as_std140(&self) -> Vec<u8> {
+        let mut bytes = vec![0; Self::STD140_SIZE];
+        self.write_std140(&mut bytes);
+        bytes
+    }
+}
+
+pub trait AsStd430 {
+    const STD430_ALIGN: usize;
+    const STD430_SIZE: usize;
+
+    // Writes the padded representation to the start of bytes, which must hold STD430_SIZE bytes
+    fn write_std430(&self, bytes: &mut [u8]);
+
+    fn as_std430(&self) -> Vec<u8> {
+        let mut bytes = vec![0; Self::STD430_SIZE];
+        self.write_std430(&mut bytes);
+        bytes
+    }
+}
+
+// Types that can be the components of GLSL vectors and matrices
+pub trait GlslScalar: Pod {}
+
+impl GlslScalar for f32 {}
+impl GlslScalar for f64 {}
+impl GlslScalar for i32 {}
+impl GlslScalar for u32 {}
+
+// Alignments are always powers of two
+#[doc(hidden)]
+pub const fn std_layout_round_up(value: usize, align: usize) -> usize {
+    (value + align - 1) & !(align - 1)
+}
+
+// Alignment of array elements, matrix columns and structs under each layout
+const fn std140_compound_align(align: usize) -> usize {
+    std_layout_round_up(align, 16)
+}
+
+const fn std430_compound_align(align: usize) -> usize {
+    align
+}
+
+macro_rules! std_layout_impls {
+    ($trait:ident, $align:ident, $size:ident, $write:ident, $compound_align:ident) => {
+        std_layout_impls!(@scalar $trait, $align, $size, $write, f32, f64, i32, u32);
+
+        // vec2 is aligned to two components, vec3 and vec4 to four
+        impl<T: GlslScalar, const N: usize> $trait for Vector<T, N> {
+            const $align: usize = match N {
+                1 => size_of::<T>(),
+                2 => 2 * size_of::<T>(),
+                _ => 4 * size_of::<T>(),
+            };
+            const $size: usize = N * size_of::<T>();
+
+            fn $write(&self, bytes: &mut [u8]) {
+                bytes[..N * size_of::<T>()].copy_from_slice(self.as_bytes());
+            }
+        }
+
+        // Matrices are laid out as arrays of their column vectors
+        impl<T: GlslScalar, const COLUMNS: usize, const ROWS: usize> $trait
+            for Matrix<T, COLUMNS, ROWS>
+        {
+            const $align: usize = $compound_align(<Vector<T, ROWS> as $trait>::$align);
+            const $size: usize = COLUMNS
+                * std_layout_round_up(<Vector<T, ROWS> as $trait>::$size, Self::$align);
+
+            fn $write(&self, bytes: &mut [u8]) {
+                let stride = Self::$size / COLUMNS;
+                for column_idx in 0..COLUMNS {
+                    self[column_idx].$write(&mut bytes[column_idx * stride..]);
+                }
+            }
+        }
+
+        impl<T: $trait, const N: usize> $trait for [T; N] {
+            const $align: usize = $compound_align(T::$align);
+            const $size: usize = N * std_layout_round_up(T::$size, Self::$align);
+
+            fn $write(&self, bytes: &mut [u8]) {
+                let stride = Self::$size / N;
+                for (idx, element) in self.iter().enumerate() {
+                    element.$write(&mut bytes[idx * stride..]);
+                }
+            }
+        }
+    };
+    (@scalar $trait:ident, $align:ident, $size:ident, $write:ident, $($scalar:ty),+) => {
+        $(
+            impl $trait for $scalar {
+                const $align: usize = size_of::<$scalar>();
+                const $size: usize = size_of::<$scalar>();
+
+                fn $write(&self, bytes: &mut [u8]) {
+                    bytes[..size_of::<$scalar>()].copy_from_slice(bytes_of(self));
+                }
+            }
+        )+
+    };
+}
+
+std_layout_impls!(
+    AsStd140,
+    STD140_ALIGN,
+    STD140_SIZE,
+    write_std140,
+    std140_compound_align
+);
+std_layout_impls!(
+    AsStd430,
+    STD430_ALIGN,
+    STD430_SIZE,
+    write_std430,
+    std430_compound_align
+);
+
+// Declares a struct and implements AsStd140 and AsStd430 for it, placing the fields in
+// declaration order as a GLSL block or struct would:
+//
+// std_layout_struct! {
+//     pub struct Light {
+//         pub position: Vector<f32, 3>,
+//         pub intensity: f32,
+//     }
+// }
+#[macro_export]
+macro_rules! std_layout_struct {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident {
+            $($field_vis:vis $field:ident: $field_type:ty),+ $(,)?
+        }
+    ) => {
+        $(#[$attr])*
+        $vis struct $name {
+            $($field_vis $field: $field_type),+
+        }
+
+        $crate::std_layout_struct!(@impl $name, 16, AsStd140, STD140_ALIGN, STD140_SIZE,
+            write_std140, $($field: $field_type),+);
+        $crate::std_layout_struct!(@impl $name, 1, AsStd430, STD430_ALIGN, STD430_SIZE,
+            write_std430, $($field: $field_type),+);
+    };
+    (
+        @impl $name:ident, $min_align:expr, $trait:ident, $align:ident, $size:ident,
+        $write:ident, $($field:ident: $field_type:ty),+
+    ) => {
+        impl $crate::$trait for $name {
+            const $align: usize = {
+                let mut align = $min_align;
+                $(
+                    if <$field_type as $crate::$trait>::$align > align {
+                        align = <$field_type as $crate::$trait>::$align;
+                    }
+                )+
+                align
+            };
+            const $size: usize = {
+                let mut offset = 0;
+                $(
+                    offset = $crate::std_layout_round_up(
+                        offset,
+                        <$field_type as $crate::$trait>::$align,
+                    ) + <$field_type as $crate::$trait>::$size;
+                )+
+                $crate::std_layout_round_up(offset, Self::$align)
+            };
+
+            fn $write(&self, bytes: &mut [u8]) {
+                let mut offset = 0;
+                $(
+                    offset = $crate::std_layout_round_up(
+                        offset,
+                        <$field_type as $crate::$trait>::$align,
+                    );
+                    $crate::$trait::$write(&self.$field, &mut bytes[offset..]);
+                    offset += <$field_type as $crate::$trait>::$size;
+                )+
+                let _ = offset;
+            }
+        }
+    };
+}
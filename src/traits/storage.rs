@@ -0,0 +1,90 @@
+use crate::{DMatrix, DVector, Matrix, Vector};
+
+// Common access to fixed and dynamically sized vectors so algorithms can be written once
+pub trait VectorStorage<T> {
+    fn as_slice(&self) -> &[T];
+    fn as_mut_slice(&mut self) -> &mut [T];
+
+    fn len(&self) -> usize {
+        self.as_slice().len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+// Common access to fixed and dynamically sized matrices. Elements are stored in column-major
+// order
+pub trait MatrixStorage<T> {
+    fn column_count(&self) -> usize;
+    fn row_count(&self) -> usize;
+    fn as_slice(&self) -> &[T];
+    fn as_mut_slice(&mut self) -> &mut [T];
+
+    fn element(&self, column: usize, row: usize) -> &T {
+        let rows = self.row_count();
+        &self.as_slice()[column * rows + row]
+    }
+
+    fn element_mut(&mut self, column: usize, row: usize) -> &mut T {
+        let rows = self.row_count();
+        &mut self.as_mut_slice()[column * rows + row]
+    }
+}
+
+impl<T, const N: usize> VectorStorage<T> for Vector<T, N> {
+    fn as_slice(&self) -> &[T] {
+        Vector::as_slice(self)
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        Vector::as_mut_slice(self)
+    }
+}
+
+impl<T> VectorStorage<T> for DVector<T> {
+    fn as_slice(&self) -> &[T] {
+        DVector::as_slice(self)
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        DVector::as_mut_slice(self)
+    }
+}
+
+impl<T, const COLUMNS: usize, const ROWS: usize> MatrixStorage<T> for Matrix<T, COLUMNS, ROWS> {
+    fn column_count(&self) -> usize {
+        COLUMNS
+    }
+
+    fn row_count(&self) -> usize {
+        ROWS
+    }
+
+    fn as_slice(&self) -> &[T] {
+        Matrix::as_slice(self)
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        Matrix::as_mut_slice(self)
+    }
+}
+
+impl<T> MatrixStorage<T> for DMatrix<T> {
+    fn column_count(&self) -> usize {
+        DMatrix::column_count(self)
+    }
+
+    fn row_count(&self) -> usize {
+        DMatrix::row_count(self)
+    }
+
+    fn as_slice(&self) -> &[T] {
+        DMatrix::as_slice(self)
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        DMatrix::as_mut_slice(self)
+    }
+}
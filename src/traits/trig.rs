@@ -0,0 +1,78 @@
+pub trait Trig: Sized {
+    fn sin(&self) -> Self;
+    fn cos(&self) -> Self;
+    fn tan(&self) -> Self;
+    fn asin(&self) -> Self;
+    fn acos(&self) -> Self;
+    fn atan(&self) -> Self;
+    fn atan2(&self, other: &Self) -> Self;
+    fn sin_cos(&self) -> (Self, Self);
+}
+
+impl Trig for f32 {
+    fn sin(&self) -> Self {
+        f32::sin(*self)
+    }
+
+    fn cos(&self) -> Self {
+        f32::cos(*self)
+    }
+
+    fn tan(&self) -> Self {
+        f32::tan(*self)
+    }
+
+    fn asin(&self) -> Self {
+        f32::asin(*self)
+    }
+
+    fn acos(&self) -> Self {
+        f32::acos(*self)
+    }
+
+    fn atan(&self) -> Self {
+        f32::atan(*self)
+    }
+
+    fn atan2(&self, other: &Self) -> Self {
+        f32::atan2(*self, *other)
+    }
+
+    fn sin_cos(&self) -> (Self, Self) {
+        f32::sin_cos(*self)
+    }
+}
+
+impl Trig for f64 {
+    fn sin(&self) -> Self {
+        f64::sin(*self)
+    }
+
+    fn cos(&self) -> Self {
+        f64::cos(*self)
+    }
+
+    fn tan(&self) -> Self {
+        f64::tan(*self)
+    }
+
+    fn asin(&self) -> Self {
+        f64::asin(*self)
+    }
+
+    fn acos(&self) -> Self {
+        f64::acos(*self)
+    }
+
+    fn atan(&self) -> Self {
+        f64::atan(*self)
+    }
+
+    fn atan2(&self, other: &Self) -> Self {
+        f64::atan2(*self, *other)
+    }
+
+    fn sin_cos(&self) -> (Self, Self) {
+        f64::sin_cos(*self)
+    }
+}
@@ -73,3 +73,15 @@ impl Two for u128 {
         2
     }
 }
+
+impl Two for isize {
+    fn two() -> Self {
+        2
+    }
+}
+
+impl Two for usize {
+    fn two() -> Self {
+        2
+    }
+}
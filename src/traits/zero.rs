@@ -73,3 +73,15 @@ impl Zero for u128 {
         0
     }
 }
+
+impl Zero for isize {
+    fn zero() -> Self {
+        0
+    }
+}
+
+impl Zero for usize {
+    fn zero() -> Self {
+        0
+    }
+}
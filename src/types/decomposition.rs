@@ -0,0 +1,674 @@
+use crate::*;
+use std::cmp::Ordering;
+
+// Iteration limit for the Jacobi methods, which normally converge within a handful of sweeps
+const MAX_SWEEPS: usize = 64;
+
+pub(crate) fn identity<T: Real, const N: usize>() -> Matrix<T, N, N> {
+    let mut identity = Matrix::new(Vector::zero());
+    for idx in 0..N {
+        identity[idx][idx] = T::one();
+    }
+    identity
+}
+
+// Like Real::max, except that NaN wins so that it reaches check_condition instead of vanishing
+fn max_or_nan<T: Real>(max: T, value: T) -> T {
+    let max_is_nan = max.partial_cmp(&max).is_none();
+    if max_is_nan || max >= value {
+        max
+    } else {
+        value
+    }
+}
+
+// Largest absolute column sum
+pub(crate) fn norm_1<T: Real, S: MatrixStorage<T>>(matrix: &S) -> T {
+    (0..matrix.column_count()).fold(T::zero(), |max, column_idx| {
+        let sum = (0..matrix.row_count()).fold(T::zero(), |sum, row_idx| {
+            sum + matrix.element(column_idx, row_idx).abs()
+        });
+        max_or_nan(max, sum)
+    })
+}
+
+fn max_abs<T: Real, const COLUMNS: usize, const ROWS: usize>(
+    matrix: &Matrix<T, COLUMNS, ROWS>,
+) -> T {
+    matrix
+        .as_slice()
+        .iter()
+        .fold(T::zero(), |max, element| max.max(&element.abs()))
+}
+
+// LU decomposition with partial pivoting, written once over MatrixStorage for both Matrix and
+// DMatrix. L and U are packed in place (L has an implicit unit diagonal) and the row swaps are
+// applied to permutation. Returns the swap parity, or None if the matrix is singular
+pub(crate) fn lu_factorize<T: Real, S: MatrixStorage<T>>(
+    lu: &mut S,
+    permutation: &mut [usize],
+) -> Option<bool> {
+    let n = lu.row_count();
+    let mut odd_swaps = false;
+    for k in 0..n {
+        let mut pivot_idx = k;
+        let mut pivot_abs = lu.element(k, k).abs();
+        for row_idx in (k + 1)..n {
+            let abs = lu.element(k, row_idx).abs();
+            if abs > pivot_abs {
+                pivot_idx = row_idx;
+                pivot_abs = abs;
+            }
+        }
+        if pivot_abs == T::zero() {
+            return None;
+        }
+        if pivot_idx != k {
+            let elements = lu.as_mut_slice();
+            for column_idx in 0..n {
+                elements.swap(column_idx * n + k, column_idx * n + pivot_idx);
+            }
+            permutation.swap(k, pivot_idx);
+            odd_swaps = !odd_swaps;
+        }
+        for row_idx in (k + 1)..n {
+            let factor = *lu.element(k, row_idx) / *lu.element(k, k);
+            for column_idx in (k + 1)..n {
+                let value = *lu.element(column_idx, row_idx) - factor * *lu.element(column_idx, k);
+                *lu.element_mut(column_idx, row_idx) = value;
+            }
+            *lu.element_mut(k, row_idx) = factor;
+        }
+    }
+    Some(odd_swaps)
+}
+
+// Solves Ax = b with the packed output of lu_factorize. Pivots are never zero since singular
+// matrices are rejected there
+pub(crate) fn lu_substitute<T: Real, S: MatrixStorage<T>>(
+    lu: &S,
+    permutation: &[usize],
+    b: &[T],
+    x: &mut [T],
+) {
+    let n = lu.row_count();
+
+    // Forward substitution with the unit lower triangle (Ly = Pb)
+    for row_idx in 0..n {
+        let mut sum = b[permutation[row_idx]];
+        for (k, x_k) in x[..row_idx].iter().enumerate() {
+            sum = sum - *lu.element(k, row_idx) * *x_k;
+        }
+        x[row_idx] = sum;
+    }
+
+    // Back substitution with the upper triangle (Ux = y)
+    for row_idx in (0..n).rev() {
+        let mut sum = x[row_idx];
+        for (k, x_k) in x.iter().enumerate().skip(row_idx + 1) {
+            sum = sum - *lu.element(k, row_idx) * *x_k;
+        }
+        x[row_idx] = sum / *lu.element(row_idx, row_idx);
+    }
+}
+
+// Jacobi rotation (cos, sin) zeroing the off-diagonal term of the 2x2 symmetric matrix
+// [[a, c], [c, b]]
+fn jacobi_rotation<T: Real>(a: T, b: T, c: T) -> (T, T) {
+    let theta = (b - a) / (T::two() * c);
+    let sign = if theta < T::zero() {
+        -T::one()
+    } else {
+        T::one()
+    };
+    let t = sign / (theta.abs() + (theta * theta + T::one()).sqrt());
+    let cos = T::one() / (t * t + T::one()).sqrt();
+    (cos, t * cos)
+}
+
+// Rotates columns p and q of a matrix by (cos, sin)
+fn rotate_columns<T: Real, const COLUMNS: usize, const ROWS: usize>(
+    matrix: &mut Matrix<T, COLUMNS, ROWS>,
+    p: usize,
+    q: usize,
+    (cos, sin): (T, T),
+) {
+    for row_idx in 0..ROWS {
+        let (a, b) = (matrix[p][row_idx], matrix[q][row_idx]);
+        matrix[p][row_idx] = cos * a - sin * b;
+        matrix[q][row_idx] = sin * a + cos * b;
+    }
+}
+
+// Indices of the values in descending order
+fn descending_order<T: Real, const N: usize>(values: &Vector<T, N>) -> [usize; N] {
+    let mut order = init_array!([usize; N], |idx| idx);
+    for idx in 0..N {
+        let mut max_idx = idx;
+        for other_idx in (idx + 1)..N {
+            if values[order[other_idx]] > values[order[max_idx]] {
+                max_idx = other_idx;
+            }
+        }
+        order.swap(idx, max_idx);
+    }
+    order
+}
+
+fn reorder_columns<T: Real, const COLUMNS: usize, const ROWS: usize>(
+    matrix: &Matrix<T, COLUMNS, ROWS>,
+    order: &[usize; COLUMNS],
+) -> Matrix<T, COLUMNS, ROWS> {
+    Matrix::from_array(init_array!([Vector<T, ROWS>; COLUMNS], |idx: usize| matrix[order[idx]]))
+}
+
+// LU decomposition with partial pivoting, PA = LU
+#[derive(Clone, Copy, Debug)]
+pub struct Lu<T: Real, const N: usize> {
+    lu: Matrix<T, N, N>,
+    permutation: [usize; N],
+    odd_swaps: bool,
+    norm: T,
+}
+
+impl<T: Real, const N: usize> Lu<T, N> {
+    pub fn l(&self) -> Matrix<T, N, N> {
+        let mut l = identity();
+        for column_idx in 0..N {
+            for row_idx in (column_idx + 1)..N {
+                l[column_idx][row_idx] = self.lu[column_idx][row_idx];
+            }
+        }
+        l
+    }
+
+    pub fn u(&self) -> Matrix<T, N, N> {
+        let mut u = Matrix::new(Vector::zero());
+        for column_idx in 0..N {
+            for row_idx in 0..=column_idx {
+                u[column_idx][row_idx] = self.lu[column_idx][row_idx];
+            }
+        }
+        u
+    }
+
+    // Row idx of PA is row permutation[idx] of A
+    pub fn permutation(&self) -> &[usize; N] {
+        &self.permutation
+    }
+
+    pub fn p(&self) -> Matrix<T, N, N> {
+        let mut p = Matrix::new(Vector::zero());
+        for (row_idx, &column_idx) in self.permutation.iter().enumerate() {
+            p[column_idx][row_idx] = T::one();
+        }
+        p
+    }
+
+    pub fn determinant(&self) -> T {
+        let det = (0..N).fold(T::one(), |det, idx| det * self.lu[idx][idx]);
+        if self.odd_swaps {
+            -det
+        } else {
+            det
+        }
+    }
+
+    // Condition number in the 1-norm, computed from the explicit inverse
+    pub fn condition_number(&self) -> T {
+        let inverse_norm = (0..N).fold(T::zero(), |max, column_idx| {
+            let mut unit = Vector::<T, N>::zero();
+            unit[column_idx] = T::one();
+            let column = self.substitute(&unit);
+            max_or_nan(
+                max,
+                column.fold(T::zero(), |sum, element| sum + element.abs()),
+            )
+        });
+        self.norm * inverse_norm
+    }
+
+    pub fn solve(&self, b: &Vector<T, N>) -> Result<Vector<T, N>, MatrixError> {
+        Ok(self.substitute(b))
+    }
+
+    pub fn solve_matrix<const C: usize>(
+        &self,
+        b: &Matrix<T, C, N>,
+    ) -> Result<Matrix<T, C, N>, MatrixError> {
+        Ok(Matrix::from_array(init_array!(
+            [Vector<T, N>; C],
+            |column_idx: usize| self.substitute(&b[column_idx])
+        )))
+    }
+
+    fn substitute(&self, b: &Vector<T, N>) -> Vector<T, N> {
+        let mut x = Vector::<T, N>::zero();
+        lu_substitute(&self.lu, &self.permutation, b.as_slice(), x.as_mut_slice());
+        x
+    }
+}
+
+// QR decomposition by Householder reflections, A = QR with Q orthogonal and R upper triangular
+#[derive(Clone, Copy, Debug)]
+pub struct Qr<T: Real, const COLUMNS: usize, const ROWS: usize> {
+    q: Matrix<T, ROWS, ROWS>,
+    r: Matrix<T, COLUMNS, ROWS>,
+}
+
+impl<T: Real, const COLUMNS: usize, const ROWS: usize> Qr<T, COLUMNS, ROWS> {
+    pub fn q(&self) -> &Matrix<T, ROWS, ROWS> {
+        &self.q
+    }
+
+    pub fn r(&self) -> &Matrix<T, COLUMNS, ROWS> {
+        &self.r
+    }
+
+    // Condition number of A in the 2-norm. Q is orthogonal, so this is the condition number of
+    // the square upper part of R. This requires at least as many rows as columns
+    pub fn condition_number(&self) -> Result<T, MatrixError> {
+        let r = self.r.fixed_view::<COLUMNS, COLUMNS>(0, 0)?.to_matrix();
+        Ok(r.svd()?.condition_number())
+    }
+
+    // Least squares solution of Ax = b, which requires A to have full column rank
+    pub fn solve(&self, b: &Vector<T, ROWS>) -> Result<Vector<T, COLUMNS>, MatrixError> {
+        if ROWS < COLUMNS {
+            return Err(MatrixError::TooFewRows);
+        }
+        let tolerance = T::epsilon() * T::from(ROWS as f32) * max_abs(&self.r);
+        let mut x = Vector::<T, COLUMNS>::zero();
+        for row_idx in (0..COLUMNS).rev() {
+            let mut sum = self.q[row_idx].dot(b);
+            for k in (row_idx + 1)..COLUMNS {
+                sum = sum - self.r[k][row_idx] * x[k];
+            }
+            let diagonal = self.r[row_idx][row_idx];
+            if diagonal.abs() <= tolerance {
+                return Err(MatrixError::Singular);
+            }
+            x[row_idx] = sum / diagonal;
+        }
+        Ok(x)
+    }
+}
+
+// Cholesky decomposition of a symmetric positive-definite matrix, A = LL^T
+#[derive(Clone, Copy, Debug)]
+pub struct Cholesky<T: Real, const N: usize> {
+    l: Matrix<T, N, N>,
+}
+
+impl<T: Real, const N: usize> Cholesky<T, N> {
+    pub fn l(&self) -> &Matrix<T, N, N> {
+        &self.l
+    }
+
+    pub fn solve(&self, b: &Vector<T, N>) -> Result<Vector<T, N>, MatrixError> {
+        let mut x = Vector::<T, N>::zero();
+
+        // Forward substitution with L (Ly = b)
+        for row_idx in 0..N {
+            let mut sum = b[row_idx];
+            for k in 0..row_idx {
+                sum = sum - self.l[k][row_idx] * x[k];
+            }
+            x[row_idx] = sum / self.l[row_idx][row_idx];
+        }
+
+        // Back substitution with L^T (L^T x = y)
+        for row_idx in (0..N).rev() {
+            let mut sum = x[row_idx];
+            for k in (row_idx + 1)..N {
+                sum = sum - self.l[row_idx][k] * x[k];
+            }
+            x[row_idx] = sum / self.l[row_idx][row_idx];
+        }
+        Ok(x)
+    }
+}
+
+// Thin singular value decomposition, A = U * diag(S) * V^T with the singular values in
+// descending order. Columns of U belonging to zero singular values are left at zero
+#[derive(Clone, Copy, Debug)]
+pub struct Svd<T: Real, const COLUMNS: usize, const ROWS: usize> {
+    u: Matrix<T, COLUMNS, ROWS>,
+    singular_values: Vector<T, COLUMNS>,
+    v: Matrix<T, COLUMNS, COLUMNS>,
+}
+
+impl<T: Real, const COLUMNS: usize, const ROWS: usize> Svd<T, COLUMNS, ROWS> {
+    pub fn u(&self) -> &Matrix<T, COLUMNS, ROWS> {
+        &self.u
+    }
+
+    pub fn singular_values(&self) -> &Vector<T, COLUMNS> {
+        &self.singular_values
+    }
+
+    pub fn v(&self) -> &Matrix<T, COLUMNS, COLUMNS> {
+        &self.v
+    }
+
+    // Singular values below this are treated as zero
+    pub fn tolerance(&self) -> T {
+        let size = if COLUMNS > ROWS { COLUMNS } else { ROWS };
+        match COLUMNS {
+            0 => T::zero(),
+            _ => T::epsilon() * T::from(size as f32) * self.singular_values[0],
+        }
+    }
+
+    // Ratio of the largest to the smallest singular value, which is infinite for matrices
+    // without full column rank
+    pub fn condition_number(&self) -> T {
+        match COLUMNS {
+            0 => T::one(),
+            _ => self.singular_values[0] / self.singular_values[COLUMNS - 1],
+        }
+    }
+
+    pub fn rank(&self) -> usize {
+        let tolerance = self.tolerance();
+        self.singular_values
+            .iter()
+            .filter(|&&value| value > tolerance)
+            .count()
+    }
+
+    // Minimum norm least squares solution of Ax = b, which exists for any A
+    pub fn solve(&self, b: &Vector<T, ROWS>) -> Result<Vector<T, COLUMNS>, MatrixError> {
+        let tolerance = self.tolerance();
+        let mut x = Vector::<T, COLUMNS>::zero();
+        for idx in 0..COLUMNS {
+            let value = self.singular_values[idx];
+            if value > tolerance {
+                let factor = self.u[idx].dot(b) / value;
+                for (x, v) in x.iter_mut().zip(self.v[idx].iter()) {
+                    *x = *x + factor * *v;
+                }
+            }
+        }
+        Ok(x)
+    }
+}
+
+// Eigendecomposition of a symmetric matrix, A = V * diag(eigenvalues) * V^T with the
+// eigenvalues in descending order and the eigenvectors in the columns of V
+#[derive(Clone, Copy, Debug)]
+pub struct SymmetricEigen<T: Real, const N: usize> {
+    eigenvalues: Vector<T, N>,
+    eigenvectors: Matrix<T, N, N>,
+}
+
+impl<T: Real, const N: usize> SymmetricEigen<T, N> {
+    pub fn eigenvalues(&self) -> &Vector<T, N> {
+        &self.eigenvalues
+    }
+
+    pub fn eigenvectors(&self) -> &Matrix<T, N, N> {
+        &self.eigenvectors
+    }
+
+    pub fn solve(&self, b: &Vector<T, N>) -> Result<Vector<T, N>, MatrixError> {
+        let largest = self
+            .eigenvalues
+            .iter()
+            .fold(T::zero(), |max, value| max.max(&value.abs()));
+        let tolerance = T::epsilon() * T::from(N as f32) * largest;
+        let mut x = Vector::<T, N>::zero();
+        for idx in 0..N {
+            let value = self.eigenvalues[idx];
+            if value.abs() <= tolerance {
+                return Err(MatrixError::Singular);
+            }
+            let factor = self.eigenvectors[idx].dot(b) / value;
+            for (x, v) in x.iter_mut().zip(self.eigenvectors[idx].iter()) {
+                *x = *x + factor * *v;
+            }
+        }
+        Ok(x)
+    }
+}
+
+// Systems whose condition number reaches the reciprocal of the machine epsilon have lost all
+// precision, so solving them is refused with MatrixError::IllConditioned
+pub(crate) fn check_condition<T: Real>(condition_number: T) -> Result<(), MatrixError> {
+    // NaN in the input leaves a NaN condition number, which fails this comparison as well
+    if condition_number * T::epsilon() < T::one() {
+        Ok(())
+    } else {
+        Err(MatrixError::IllConditioned)
+    }
+}
+
+impl<T: Real, const COLUMNS: usize, const ROWS: usize> Matrix<T, COLUMNS, ROWS> {
+    // Least squares solution of the overdetermined system Ax = b through the QR decomposition
+    pub fn least_squares(&self, b: &Vector<T, ROWS>) -> Result<Vector<T, COLUMNS>, MatrixError> {
+        let qr = self.qr();
+        check_condition(qr.condition_number()?)?;
+        qr.solve(b)
+    }
+
+    // Condition number in the 2-norm through the singular value decomposition
+    pub fn condition_number(&self) -> Result<T, MatrixError> {
+        Ok(self.svd()?.condition_number())
+    }
+
+    pub fn qr(&self) -> Qr<T, COLUMNS, ROWS> {
+        let mut q = identity::<T, ROWS>();
+        let mut r = *self;
+        let mut householder = [T::zero(); ROWS];
+        for k in 0..ROWS.saturating_sub(1).min(COLUMNS) {
+            // Reflect the part of column k below the diagonal onto the diagonal
+            let norm = (k..ROWS)
+                .fold(T::zero(), |sum, row_idx| {
+                    sum + r[k][row_idx] * r[k][row_idx]
+                })
+                .sqrt();
+            if norm == T::zero() {
+                continue;
+            }
+            let alpha = if r[k][k] > T::zero() { -norm } else { norm };
+            let v = &mut householder[k..ROWS];
+            v.copy_from_slice(&r[k].as_slice()[k..]);
+            v[0] = v[0] - alpha;
+            let v = &*v;
+            let scale = T::two() / v.iter().fold(T::zero(), |sum, &v| sum + v * v);
+
+            // R = HR
+            for column_idx in k..COLUMNS {
+                let factor = scale
+                    * v.iter()
+                        .enumerate()
+                        .fold(T::zero(), |sum, (idx, &v)| sum + v * r[column_idx][k + idx]);
+                for (idx, &v) in v.iter().enumerate() {
+                    r[column_idx][k + idx] = r[column_idx][k + idx] - factor * v;
+                }
+            }
+
+            // Q = QH
+            for row_idx in 0..ROWS {
+                let factor = scale
+                    * v.iter()
+                        .enumerate()
+                        .fold(T::zero(), |sum, (idx, &v)| sum + v * q[k + idx][row_idx]);
+                for (idx, &v) in v.iter().enumerate() {
+                    q[k + idx][row_idx] = q[k + idx][row_idx] - factor * v;
+                }
+            }
+
+            r[k][k] = alpha;
+            for row_idx in (k + 1)..ROWS {
+                r[k][row_idx] = T::zero();
+            }
+        }
+        Qr { q, r }
+    }
+
+    // One-sided Jacobi: columns are rotated pairwise until they are orthogonal, leaving U*S in
+    // place of A and the accumulated rotations in V
+    pub fn svd(&self) -> Result<Svd<T, COLUMNS, ROWS>, MatrixError> {
+        let mut u = *self;
+        let mut v = identity::<T, COLUMNS>();
+        let mut converged = false;
+        for _ in 0..MAX_SWEEPS {
+            converged = true;
+            for p in 0..COLUMNS {
+                for q in (p + 1)..COLUMNS {
+                    let alpha = u[p].dot(&u[p]);
+                    let beta = u[q].dot(&u[q]);
+                    let gamma = u[p].dot(&u[q]);
+                    if gamma.abs() <= T::epsilon() * (alpha * beta).sqrt() {
+                        continue;
+                    }
+                    converged = false;
+                    let rotation = jacobi_rotation(alpha, beta, gamma);
+                    rotate_columns(&mut u, p, q, rotation);
+                    rotate_columns(&mut v, p, q, rotation);
+                }
+            }
+            if converged {
+                break;
+            }
+        }
+        if !converged {
+            return Err(MatrixError::NoConvergence);
+        }
+
+        let lengths = Vector::new(init_array!([T; COLUMNS], |idx: usize| u[idx]
+            .dot(&u[idx])
+            .sqrt()));
+        let order = descending_order(&lengths);
+        let singular_values =
+            Vector::new(init_array!([T; COLUMNS], |idx: usize| lengths[order[idx]]));
+
+        // Vanishing singular values leave only rounding noise in their columns of U
+        let tolerance = T::epsilon() * singular_values.iter().fold(T::zero(), |max, s| max.max(s));
+        let mut u = reorder_columns(&u, &order);
+        for idx in 0..COLUMNS {
+            let value = singular_values[idx];
+            u[idx] = if value > tolerance {
+                u[idx].map(|element| element / value)
+            } else {
+                Vector::zero()
+            };
+        }
+        Ok(Svd {
+            u,
+            singular_values,
+            v: reorder_columns(&v, &order),
+        })
+    }
+}
+
+impl<T: Real, const N: usize> Matrix<T, N, N> {
+    pub fn lu(&self) -> Result<Lu<T, N>, MatrixError> {
+        let (lu, permutation, odd_swaps) = self.lu_decomposed().ok_or(MatrixError::Singular)?;
+        Ok(Lu {
+            lu,
+            permutation,
+            odd_swaps,
+            norm: norm_1(self),
+        })
+    }
+
+    pub fn solve(&self, b: &Vector<T, N>) -> Result<Vector<T, N>, MatrixError> {
+        let lu = self.lu()?;
+        check_condition(lu.condition_number())?;
+        lu.solve(b)
+    }
+
+    // Solves AX = B for every column of B at once
+    pub fn solve_matrix<const C: usize>(
+        &self,
+        b: &Matrix<T, C, N>,
+    ) -> Result<Matrix<T, C, N>, MatrixError> {
+        let lu = self.lu()?;
+        check_condition(lu.condition_number())?;
+        lu.solve_matrix(b)
+    }
+
+    pub fn cholesky(&self) -> Result<Cholesky<T, N>, MatrixError> {
+        self.check_symmetric()?;
+        let mut l = Matrix::new(Vector::zero());
+        for column_idx in 0..N {
+            let mut diagonal = self[column_idx][column_idx];
+            for k in 0..column_idx {
+                diagonal = diagonal - l[k][column_idx] * l[k][column_idx];
+            }
+            // NaN fails this comparison as well
+            if diagonal.partial_cmp(&T::zero()) != Some(Ordering::Greater) {
+                return Err(MatrixError::NotPositiveDefinite);
+            }
+            let diagonal = diagonal.sqrt();
+            l[column_idx][column_idx] = diagonal;
+            for row_idx in (column_idx + 1)..N {
+                let mut sum = self[column_idx][row_idx];
+                for k in 0..column_idx {
+                    sum = sum - l[k][row_idx] * l[k][column_idx];
+                }
+                l[column_idx][row_idx] = sum / diagonal;
+            }
+        }
+        Ok(Cholesky { l })
+    }
+
+    // Cyclic Jacobi rotations, each zeroing one off-diagonal pair until the matrix is diagonal
+    pub fn symmetric_eigen(&self) -> Result<SymmetricEigen<T, N>, MatrixError> {
+        self.check_symmetric()?;
+        let mut a = *self;
+        let mut v = identity::<T, N>();
+        let tolerance = T::epsilon() * max_abs(self);
+        let mut converged = false;
+        for _ in 0..MAX_SWEEPS {
+            converged = true;
+            for p in 0..N {
+                for q in (p + 1)..N {
+                    if a[q][p].abs() <= tolerance {
+                        continue;
+                    }
+                    converged = false;
+                    let (cos, sin) = jacobi_rotation(a[p][p], a[q][q], a[q][p]);
+
+                    // A = J^T A J
+                    rotate_columns(&mut a, p, q, (cos, sin));
+                    for column_idx in 0..N {
+                        let (x, y) = (a[column_idx][p], a[column_idx][q]);
+                        a[column_idx][p] = cos * x - sin * y;
+                        a[column_idx][q] = sin * x + cos * y;
+                    }
+                    a[q][p] = T::zero();
+                    a[p][q] = T::zero();
+                    rotate_columns(&mut v, p, q, (cos, sin));
+                }
+            }
+            if converged {
+                break;
+            }
+        }
+        if !converged {
+            return Err(MatrixError::NoConvergence);
+        }
+
+        let diagonal = Vector::new(init_array!([T; N], |idx: usize| a[idx][idx]));
+        let order = descending_order(&diagonal);
+        Ok(SymmetricEigen {
+            eigenvalues: Vector::new(init_array!([T; N], |idx: usize| diagonal[order[idx]])),
+            eigenvectors: reorder_columns(&v, &order),
+        })
+    }
+
+    fn check_symmetric(&self) -> Result<(), MatrixError> {
+        let tolerance = T::epsilon() * T::from(N as f32) * max_abs(self);
+        for column_idx in 0..N {
+            for row_idx in (column_idx + 1)..N {
+                if (self[column_idx][row_idx] - self[row_idx][column_idx]).abs() > tolerance {
+                    return Err(MatrixError::NotSymmetric);
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,488 @@
+use crate::*;
+use std::convert::TryFrom;
+use std::iter::Sum;
+use std::ops::{Add, Div, Index, IndexMut, Mul, Rem, Sub};
+
+// Heap-allocated column-major matrix whose size is only known at runtime. Operations between
+// mismatched matrices return MatrixError::DimensionMismatch where Matrix would fail to compile
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DMatrix<T> {
+    columns: usize,
+    rows: usize,
+    elements: Vec<T>,
+}
+
+impl<T> DMatrix<T> {
+    // Elements are given in column-major order
+    pub fn new(columns: usize, rows: usize, elements: Vec<T>) -> Result<Self, MatrixError> {
+        if elements.len() != columns * rows {
+            return Err(MatrixError::IncorrectElementCount);
+        }
+        Ok(Self {
+            columns,
+            rows,
+            elements,
+        })
+    }
+
+    pub fn from_columns(columns: &[DVector<T>]) -> Result<Self, MatrixError>
+    where
+        T: Clone,
+    {
+        let rows = columns.first().map_or(0, |column| column.len());
+        if columns.iter().any(|column| column.len() != rows) {
+            return Err(MatrixError::DimensionMismatch);
+        }
+        let elements = columns
+            .iter()
+            .flat_map(|column| column.iter().cloned())
+            .collect();
+        Self::new(columns.len(), rows, elements)
+    }
+
+    pub fn zero(columns: usize, rows: usize) -> Self
+    where
+        T: Zero + Clone,
+    {
+        Self {
+            columns,
+            rows,
+            elements: vec![T::zero(); columns * rows],
+        }
+    }
+
+    pub fn identity(size: usize) -> Self
+    where
+        T: Zero + One + Clone,
+    {
+        let mut identity = Self::zero(size, size);
+        for idx in 0..size {
+            identity[idx][idx] = T::one();
+        }
+        identity
+    }
+
+    pub fn row_length(&self) -> usize {
+        self.columns
+    }
+
+    pub fn row_count(&self) -> usize {
+        self.rows
+    }
+
+    pub fn column_length(&self) -> usize {
+        self.rows
+    }
+
+    pub fn column_count(&self) -> usize {
+        self.columns
+    }
+
+    pub fn is_square(&self) -> bool {
+        self.columns == self.rows
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        &self.elements
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.elements
+    }
+
+    pub fn column(&self, idx: usize) -> &[T] {
+        &self.elements[idx * self.rows..(idx + 1) * self.rows]
+    }
+
+    pub fn column_mut(&mut self, idx: usize) -> &mut [T] {
+        let rows = self.rows;
+        &mut self.elements[idx * rows..(idx + 1) * rows]
+    }
+
+    pub fn row(&self, idx: usize) -> DVector<T>
+    where
+        T: Clone,
+    {
+        (0..self.columns)
+            .map(|column_idx| self[column_idx][idx].clone())
+            .collect()
+    }
+
+    pub fn transposed(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            columns: self.rows,
+            rows: self.columns,
+            elements: (0..self.rows)
+                .flat_map(|row_idx| (0..self.columns).map(move |column_idx| (column_idx, row_idx)))
+                .map(|(column_idx, row_idx)| self[column_idx][row_idx].clone())
+                .collect(),
+        }
+    }
+
+    fn check_same_size(&self, other: &Self) -> Result<(), MatrixError> {
+        if self.columns == other.columns && self.rows == other.rows {
+            Ok(())
+        } else {
+            Err(MatrixError::DimensionMismatch)
+        }
+    }
+
+    fn check_square(&self) -> Result<(), MatrixError> {
+        if self.is_square() {
+            Ok(())
+        } else {
+            Err(MatrixError::NotSquare)
+        }
+    }
+
+    pub fn add_matrix(&mut self, other: &Self) -> Result<(), MatrixError>
+    where
+        T: Add<T, Output = T> + Clone,
+    {
+        self.check_same_size(other)?;
+        for (a, b) in self.elements.iter_mut().zip(other.elements.iter()) {
+            *a = a.clone() + b.clone();
+        }
+        Ok(())
+    }
+
+    pub fn sub_matrix(&mut self, other: &Self) -> Result<(), MatrixError>
+    where
+        T: Sub<T, Output = T> + Clone,
+    {
+        self.check_same_size(other)?;
+        for (a, b) in self.elements.iter_mut().zip(other.elements.iter()) {
+            *a = a.clone() - b.clone();
+        }
+        Ok(())
+    }
+
+    pub fn div_matrix(&mut self, other: &Self) -> Result<(), MatrixError>
+    where
+        T: Div<T, Output = T> + Clone,
+    {
+        self.check_same_size(other)?;
+        for (a, b) in self.elements.iter_mut().zip(other.elements.iter()) {
+            *a = a.clone() / b.clone();
+        }
+        Ok(())
+    }
+
+    pub fn rem_matrix(&mut self, other: &Self) -> Result<(), MatrixError>
+    where
+        T: Rem<T, Output = T> + Clone,
+    {
+        self.check_same_size(other)?;
+        for (a, b) in self.elements.iter_mut().zip(other.elements.iter()) {
+            *a = a.clone() % b.clone();
+        }
+        Ok(())
+    }
+
+    pub fn mul_matrix(&self, other: &Self) -> Result<Self, MatrixError>
+    where
+        T: Mul<T, Output = T> + Clone + Sum,
+    {
+        if other.rows != self.columns {
+            return Err(MatrixError::DimensionMismatch);
+        }
+        let mut elements = Vec::with_capacity(other.columns * self.rows);
+        for column_idx in 0..other.columns {
+            elements.extend(self.mul_slice(other.column(column_idx)));
+        }
+        Self::new(other.columns, self.rows, elements)
+    }
+
+    pub fn mul_vector(&self, vector: &DVector<T>) -> Result<DVector<T>, MatrixError>
+    where
+        T: Mul<T, Output = T> + Clone + Sum,
+    {
+        if vector.len() != self.columns {
+            return Err(MatrixError::DimensionMismatch);
+        }
+        Ok(self.mul_slice(vector.as_slice()).collect())
+    }
+
+    fn mul_slice<'a>(&'a self, vector: &'a [T]) -> impl Iterator<Item = T> + 'a
+    where
+        T: Mul<T, Output = T> + Clone + Sum,
+    {
+        (0..self.rows).map(move |row_idx| {
+            vector
+                .iter()
+                .enumerate()
+                .map(|(column_idx, b)| self[column_idx][row_idx].clone() * b.clone())
+                .sum()
+        })
+    }
+
+    pub fn determinant(&self) -> Result<T, MatrixError>
+    where
+        T: Real,
+    {
+        self.check_square()?;
+        Ok(match self.lu_decomposed() {
+            Some((lu, _, odd_swaps)) => {
+                let det = (0..self.rows).fold(T::one(), |det, idx| det * lu[idx][idx]);
+                if odd_swaps {
+                    -det
+                } else {
+                    det
+                }
+            }
+            None => T::zero(),
+        })
+    }
+
+    pub fn inverse(&self) -> Result<Self, MatrixError>
+    where
+        T: Real,
+    {
+        self.check_square()?;
+        let (lu, permutation, _) = self.lu_decomposed().ok_or(MatrixError::Singular)?;
+        Ok(lu.lu_inverse(&permutation))
+    }
+
+    pub fn solve(&self, b: &DVector<T>) -> Result<DVector<T>, MatrixError>
+    where
+        T: Real,
+    {
+        self.check_square()?;
+        if b.len() != self.rows {
+            return Err(MatrixError::DimensionMismatch);
+        }
+        let (lu, permutation, _) = self.lu_decomposed().ok_or(MatrixError::Singular)?;
+
+        // Same 1-norm condition check as Matrix::solve
+        check_condition(norm_1(self) * norm_1(&lu.lu_inverse(&permutation)))?;
+        let mut x = DVector::zero(self.rows);
+        lu_substitute(&lu, &permutation, b.as_slice(), x.as_mut_slice());
+        Ok(x)
+    }
+
+    // LU decomposition with partial pivoting, packed the same way as for Matrix
+    fn lu_decomposed(&self) -> Option<(Self, Vec<usize>, bool)>
+    where
+        T: Real,
+    {
+        let mut lu = self.clone();
+        let mut permutation: Vec<usize> = (0..self.rows).collect();
+        let odd_swaps = lu_factorize(&mut lu, &mut permutation)?;
+        Some((lu, permutation, odd_swaps))
+    }
+
+    // Inverse of the matrix these packed LU factors belong to
+    fn lu_inverse(&self, permutation: &[usize]) -> Self
+    where
+        T: Real,
+    {
+        let mut elements = Vec::with_capacity(self.elements.len());
+        for column_idx in 0..self.columns {
+            let mut unit = DVector::zero(self.rows);
+            unit[column_idx] = T::one();
+            let mut column = DVector::zero(self.rows);
+            lu_substitute(self, permutation, unit.as_slice(), column.as_mut_slice());
+            elements.extend(column);
+        }
+        Self {
+            columns: self.columns,
+            rows: self.rows,
+            elements,
+        }
+    }
+}
+
+impl<T: Clone, const COLUMNS: usize, const ROWS: usize> From<Matrix<T, COLUMNS, ROWS>>
+    for DMatrix<T>
+{
+    fn from(matrix: Matrix<T, COLUMNS, ROWS>) -> Self {
+        Self {
+            columns: COLUMNS,
+            rows: ROWS,
+            elements: matrix.as_slice().to_vec(),
+        }
+    }
+}
+
+impl<T: Clone, const COLUMNS: usize, const ROWS: usize> TryFrom<DMatrix<T>>
+    for Matrix<T, COLUMNS, ROWS>
+{
+    type Error = MatrixError;
+
+    fn try_from(matrix: DMatrix<T>) -> Result<Self, MatrixError> {
+        if matrix.columns != COLUMNS || matrix.rows != ROWS {
+            return Err(MatrixError::DimensionMismatch);
+        }
+        Matrix::from_slice(matrix.as_slice())
+    }
+}
+
+// Indexing yields a column, so elements are addressed as matrix[column][row] like Matrix
+impl<T> Index<usize> for DMatrix<T> {
+    type Output = [T];
+
+    fn index(&self, idx: usize) -> &[T] {
+        self.column(idx)
+    }
+}
+
+impl<T> IndexMut<usize> for DMatrix<T> {
+    fn index_mut(&mut self, idx: usize) -> &mut [T] {
+        self.column_mut(idx)
+    }
+}
+
+impl<T: ApproxEq> ApproxEq for DMatrix<T> {
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> T::Epsilon {
+        T::default_epsilon()
+    }
+
+    fn default_max_relative() -> T::Epsilon {
+        T::default_max_relative()
+    }
+
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: T::Epsilon) -> bool {
+        self.check_same_size(other).is_ok()
+            && self
+                .elements
+                .iter()
+                .zip(other.elements.iter())
+                .all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: T::Epsilon, max_relative: T::Epsilon) -> bool {
+        self.check_same_size(other).is_ok()
+            && self
+                .elements
+                .iter()
+                .zip(other.elements.iter())
+                .all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+        self.check_same_size(other).is_ok()
+            && self
+                .elements
+                .iter()
+                .zip(other.elements.iter())
+                .all(|(a, b)| a.ulps_eq(b, epsilon, max_ulps))
+    }
+}
+
+macro_rules! dmatrix_binary_op {
+    ($op:ident, $fn_name:ident, $type_method:ident) => {
+        impl<'a, 'b, T> $op<&'b DMatrix<T>> for &'a DMatrix<T>
+        where
+            T: $op<T, Output = T> + Clone,
+        {
+            type Output = Result<DMatrix<T>, MatrixError>;
+
+            fn $fn_name(self, rhs: &'b DMatrix<T>) -> Result<DMatrix<T>, MatrixError> {
+                let mut new = self.clone();
+                new.$type_method(rhs)?;
+                Ok(new)
+            }
+        }
+
+        impl<'a, T> $op<&'a DMatrix<T>> for DMatrix<T>
+        where
+            T: $op<T, Output = T> + Clone,
+        {
+            type Output = Result<DMatrix<T>, MatrixError>;
+
+            fn $fn_name(mut self, rhs: &'a DMatrix<T>) -> Result<DMatrix<T>, MatrixError> {
+                self.$type_method(rhs)?;
+                Ok(self)
+            }
+        }
+
+        impl<'a, T> $op<DMatrix<T>> for &'a DMatrix<T>
+        where
+            T: $op<T, Output = T> + Clone,
+        {
+            type Output = Result<DMatrix<T>, MatrixError>;
+
+            fn $fn_name(self, rhs: DMatrix<T>) -> Result<DMatrix<T>, MatrixError> {
+                let mut new = self.clone();
+                new.$type_method(&rhs)?;
+                Ok(new)
+            }
+        }
+
+        impl<T> $op<DMatrix<T>> for DMatrix<T>
+        where
+            T: $op<T, Output = T> + Clone,
+        {
+            type Output = Result<DMatrix<T>, MatrixError>;
+
+            fn $fn_name(mut self, rhs: DMatrix<T>) -> Result<DMatrix<T>, MatrixError> {
+                self.$type_method(&rhs)?;
+                Ok(self)
+            }
+        }
+    };
+}
+
+macro_rules! dmatrix_mul_op {
+    ($rhs:ident, $type_method:ident) => {
+        impl<'a, 'b, T> Mul<&'b $rhs<T>> for &'a DMatrix<T>
+        where
+            T: Mul<T, Output = T> + Clone + Sum,
+        {
+            type Output = Result<$rhs<T>, MatrixError>;
+
+            fn mul(self, rhs: &'b $rhs<T>) -> Result<$rhs<T>, MatrixError> {
+                self.$type_method(rhs)
+            }
+        }
+
+        impl<'a, T> Mul<&'a $rhs<T>> for DMatrix<T>
+        where
+            T: Mul<T, Output = T> + Clone + Sum,
+        {
+            type Output = Result<$rhs<T>, MatrixError>;
+
+            fn mul(self, rhs: &'a $rhs<T>) -> Result<$rhs<T>, MatrixError> {
+                self.$type_method(rhs)
+            }
+        }
+
+        impl<'a, T> Mul<$rhs<T>> for &'a DMatrix<T>
+        where
+            T: Mul<T, Output = T> + Clone + Sum,
+        {
+            type Output = Result<$rhs<T>, MatrixError>;
+
+            fn mul(self, rhs: $rhs<T>) -> Result<$rhs<T>, MatrixError> {
+                self.$type_method(&rhs)
+            }
+        }
+
+        impl<T> Mul<$rhs<T>> for DMatrix<T>
+        where
+            T: Mul<T, Output = T> + Clone + Sum,
+        {
+            type Output = Result<$rhs<T>, MatrixError>;
+
+            fn mul(self, rhs: $rhs<T>) -> Result<$rhs<T>, MatrixError> {
+                self.$type_method(&rhs)
+            }
+        }
+    };
+}
+
+dmatrix_binary_op!(Add, add, add_matrix);
+dmatrix_binary_op!(Sub, sub, sub_matrix);
+dmatrix_mul_op!(DMatrix, mul_matrix);
+dmatrix_mul_op!(DVector, mul_vector);
+dmatrix_binary_op!(Div, div, div_matrix);
+dmatrix_binary_op!(Rem, rem, rem_matrix);
@@ -0,0 +1,483 @@
+use crate::*;
+use std::convert::TryFrom;
+use std::iter::{FromIterator, Sum};
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Rem, RemAssign, Sub,
+    SubAssign,
+};
+
+// Heap-allocated vector whose length is only known at runtime. Operations between two vectors
+// return VectorError::DimensionMismatch where Vector would fail to compile
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct DVector<T> {
+    pub components: Vec<T>,
+}
+
+impl<T> DVector<T> {
+    pub fn new(components: Vec<T>) -> Self {
+        Self { components }
+    }
+
+    pub fn from_slice(components: &[T]) -> Self
+    where
+        T: Clone,
+    {
+        Self::new(components.to_vec())
+    }
+
+    pub fn zero(len: usize) -> Self
+    where
+        T: Zero + Clone,
+    {
+        Self::new(vec![T::zero(); len])
+    }
+
+    pub fn len(&self) -> usize {
+        self.components.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.components.is_empty()
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        &self.components
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.components
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.components.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.components.iter_mut()
+    }
+
+    pub fn map<U, F: FnMut(T) -> U>(self, f: F) -> DVector<U> {
+        self.components.into_iter().map(f).collect()
+    }
+
+    pub fn zip_with<U, V, F: FnMut(T, U) -> V>(
+        self,
+        other: DVector<U>,
+        mut f: F,
+    ) -> Result<DVector<V>, VectorError> {
+        self.check_len(other.len())?;
+        Ok(self
+            .components
+            .into_iter()
+            .zip(other.components)
+            .map(|(a, b)| f(a, b))
+            .collect())
+    }
+
+    fn check_len(&self, len: usize) -> Result<(), VectorError> {
+        if self.len() == len {
+            Ok(())
+        } else {
+            Err(VectorError::DimensionMismatch)
+        }
+    }
+
+    pub fn add_vector(&mut self, other: &Self) -> Result<(), VectorError>
+    where
+        T: Add<T, Output = T> + Clone,
+    {
+        self.check_len(other.len())?;
+        for (a, b) in self.iter_mut().zip(other) {
+            *a = a.clone() + b.clone();
+        }
+        Ok(())
+    }
+
+    pub fn sub_vector(&mut self, other: &Self) -> Result<(), VectorError>
+    where
+        T: Sub<T, Output = T> + Clone,
+    {
+        self.check_len(other.len())?;
+        for (a, b) in self.iter_mut().zip(other) {
+            *a = a.clone() - b.clone();
+        }
+        Ok(())
+    }
+
+    pub fn mul_vector(&mut self, other: &Self) -> Result<(), VectorError>
+    where
+        T: Mul<T, Output = T> + Clone,
+    {
+        self.check_len(other.len())?;
+        for (a, b) in self.iter_mut().zip(other) {
+            *a = a.clone() * b.clone();
+        }
+        Ok(())
+    }
+
+    pub fn div_vector(&mut self, other: &Self) -> Result<(), VectorError>
+    where
+        T: Div<T, Output = T> + Clone,
+    {
+        self.check_len(other.len())?;
+        for (a, b) in self.iter_mut().zip(other) {
+            *a = a.clone() / b.clone();
+        }
+        Ok(())
+    }
+
+    pub fn rem_vector(&mut self, other: &Self) -> Result<(), VectorError>
+    where
+        T: Rem<T, Output = T> + Clone,
+    {
+        self.check_len(other.len())?;
+        for (a, b) in self.iter_mut().zip(other) {
+            *a = a.clone() % b.clone();
+        }
+        Ok(())
+    }
+
+    pub fn add_component(&mut self, other: &T)
+    where
+        T: Add<T, Output = T> + Clone,
+    {
+        for a in self.iter_mut() {
+            *a = a.clone() + other.clone();
+        }
+    }
+
+    pub fn sub_component(&mut self, other: &T)
+    where
+        T: Sub<T, Output = T> + Clone,
+    {
+        for a in self.iter_mut() {
+            *a = a.clone() - other.clone();
+        }
+    }
+
+    pub fn mul_component(&mut self, other: &T)
+    where
+        T: Mul<T, Output = T> + Clone,
+    {
+        for a in self.iter_mut() {
+            *a = a.clone() * other.clone();
+        }
+    }
+
+    pub fn div_component(&mut self, other: &T)
+    where
+        T: Div<T, Output = T> + Clone,
+    {
+        for a in self.iter_mut() {
+            *a = a.clone() / other.clone();
+        }
+    }
+
+    pub fn rem_component(&mut self, other: &T)
+    where
+        T: Rem<T, Output = T> + Clone,
+    {
+        for a in self.iter_mut() {
+            *a = a.clone() % other.clone();
+        }
+    }
+
+    pub fn dot(&self, other: &Self) -> Result<T, VectorError>
+    where
+        T: Mul<T, Output = T> + Clone + Sum,
+    {
+        self.check_len(other.len())?;
+        Ok(self
+            .iter()
+            .zip(other)
+            .map(|(a, b)| a.clone() * b.clone())
+            .sum())
+    }
+
+    // Like Vector::length2, an empty vector has no length
+    pub fn length2(&self) -> Result<T, VectorError>
+    where
+        T: Mul<T, Output = T> + Clone + Sum,
+    {
+        if self.is_empty() {
+            return Err(VectorError::ZeroComponents);
+        }
+        Ok(self.iter().map(|a| a.clone() * a.clone()).sum())
+    }
+
+    pub fn length(&self) -> Result<T, VectorError>
+    where
+        T: Real,
+    {
+        Ok(self.length2()?.sqrt())
+    }
+
+    pub fn normalized(&self) -> Result<Self, VectorError>
+    where
+        T: Real,
+    {
+        let length = self.length()?;
+        if length == T::zero() {
+            return Err(VectorError::ZeroLength);
+        }
+        Ok(self.iter().map(|&a| a / length).collect())
+    }
+}
+
+impl<T: Clone, const N: usize> From<Vector<T, N>> for DVector<T> {
+    fn from(vector: Vector<T, N>) -> Self {
+        Self::from_slice(vector.as_slice())
+    }
+}
+
+impl<T: Clone, const N: usize> TryFrom<DVector<T>> for Vector<T, N> {
+    type Error = VectorError;
+
+    fn try_from(vector: DVector<T>) -> Result<Self, VectorError> {
+        if vector.len() != N {
+            return Err(VectorError::DimensionMismatch);
+        }
+        Vector::try_from_iter(vector.components)
+    }
+}
+
+impl<T> FromIterator<T> for DVector<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+impl<T> IntoIterator for DVector<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.components.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a DVector<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.components.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut DVector<T> {
+    type Item = &'a mut T;
+    type IntoIter = std::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.components.iter_mut()
+    }
+}
+
+impl<T> Index<usize> for DVector<T> {
+    type Output = T;
+
+    fn index(&self, idx: usize) -> &T {
+        &self.components[idx]
+    }
+}
+
+impl<T> IndexMut<usize> for DVector<T> {
+    fn index_mut(&mut self, idx: usize) -> &mut T {
+        &mut self.components[idx]
+    }
+}
+
+impl<T: ApproxEq> ApproxEq for DVector<T> {
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> T::Epsilon {
+        T::default_epsilon()
+    }
+
+    fn default_max_relative() -> T::Epsilon {
+        T::default_max_relative()
+    }
+
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: T::Epsilon) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other)
+                .all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: T::Epsilon, max_relative: T::Epsilon) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other)
+                .all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other)
+                .all(|(a, b)| a.ulps_eq(b, epsilon, max_ulps))
+    }
+}
+
+impl<T: Neg> Neg for DVector<T> {
+    type Output = DVector<<T as Neg>::Output>;
+
+    fn neg(self) -> DVector<<T as Neg>::Output> {
+        self.map(|component| -component)
+    }
+}
+
+impl<T: Neg + Clone> Neg for &DVector<T> {
+    type Output = DVector<<T as Neg>::Output>;
+
+    fn neg(self) -> DVector<<T as Neg>::Output> {
+        self.iter().map(|component| -component.clone()).collect()
+    }
+}
+
+macro_rules! dvector_binary_op {
+    ($op:ident, $fn_name:ident, $type_method_component:ident, $type_method:ident) => {
+        impl<'a, 'b, T> $op<&'b DVector<T>> for &'a DVector<T>
+        where
+            T: $op<T, Output = T> + Clone,
+        {
+            type Output = Result<DVector<T>, VectorError>;
+
+            fn $fn_name(self, rhs: &'b DVector<T>) -> Result<DVector<T>, VectorError> {
+                let mut new = self.clone();
+                new.$type_method(rhs)?;
+                Ok(new)
+            }
+        }
+
+        impl<'a, T> $op<&'a DVector<T>> for DVector<T>
+        where
+            T: $op<T, Output = T> + Clone,
+        {
+            type Output = Result<DVector<T>, VectorError>;
+
+            fn $fn_name(mut self, rhs: &'a DVector<T>) -> Result<DVector<T>, VectorError> {
+                self.$type_method(rhs)?;
+                Ok(self)
+            }
+        }
+
+        impl<'a, T> $op<DVector<T>> for &'a DVector<T>
+        where
+            T: $op<T, Output = T> + Clone,
+        {
+            type Output = Result<DVector<T>, VectorError>;
+
+            fn $fn_name(self, rhs: DVector<T>) -> Result<DVector<T>, VectorError> {
+                let mut new = self.clone();
+                new.$type_method(&rhs)?;
+                Ok(new)
+            }
+        }
+
+        impl<T> $op<DVector<T>> for DVector<T>
+        where
+            T: $op<T, Output = T> + Clone,
+        {
+            type Output = Result<DVector<T>, VectorError>;
+
+            fn $fn_name(mut self, rhs: DVector<T>) -> Result<DVector<T>, VectorError> {
+                self.$type_method(&rhs)?;
+                Ok(self)
+            }
+        }
+
+        impl<'a, 'b, T> $op<&'b T> for &'a DVector<T>
+        where
+            T: $op<T, Output = T> + Clone,
+        {
+            type Output = DVector<T>;
+
+            fn $fn_name(self, rhs: &'b T) -> DVector<T> {
+                let mut new = self.clone();
+                new.$type_method_component(rhs);
+                new
+            }
+        }
+
+        impl<'a, T> $op<&'a T> for DVector<T>
+        where
+            T: $op<T, Output = T> + Clone,
+        {
+            type Output = DVector<T>;
+
+            fn $fn_name(mut self, rhs: &'a T) -> DVector<T> {
+                self.$type_method_component(rhs);
+                self
+            }
+        }
+
+        impl<'a, T> $op<T> for &'a DVector<T>
+        where
+            T: $op<T, Output = T> + Clone,
+        {
+            type Output = DVector<T>;
+
+            fn $fn_name(self, rhs: T) -> DVector<T> {
+                let mut new = self.clone();
+                new.$type_method_component(&rhs);
+                new
+            }
+        }
+
+        impl<T> $op<T> for DVector<T>
+        where
+            T: $op<T, Output = T> + Clone,
+        {
+            type Output = DVector<T>;
+
+            fn $fn_name(mut self, rhs: T) -> DVector<T> {
+                self.$type_method_component(&rhs);
+                self
+            }
+        }
+    };
+}
+
+// Only the scalar forms can be assignments since a mismatched vector has no error to report
+macro_rules! dvector_assign_op {
+    ($op:ident, $op_assign:ident, $fn_name:ident, $type_method_component:ident) => {
+        impl<T> $op_assign<&T> for DVector<T>
+        where
+            T: $op<T, Output = T> + Clone,
+        {
+            fn $fn_name(&mut self, other: &T) {
+                self.$type_method_component(other);
+            }
+        }
+
+        impl<T> $op_assign<T> for DVector<T>
+        where
+            T: $op<T, Output = T> + Clone,
+        {
+            fn $fn_name(&mut self, other: T) {
+                self.$type_method_component(&other);
+            }
+        }
+    };
+}
+
+dvector_binary_op!(Add, add, add_component, add_vector);
+dvector_binary_op!(Sub, sub, sub_component, sub_vector);
+dvector_binary_op!(Mul, mul, mul_component, mul_vector);
+dvector_binary_op!(Div, div, div_component, div_vector);
+dvector_binary_op!(Rem, rem, rem_component, rem_vector);
+dvector_assign_op!(Add, AddAssign, add_assign, add_component);
+dvector_assign_op!(Sub, SubAssign, sub_assign, sub_component);
+dvector_assign_op!(Mul, MulAssign, mul_assign, mul_component);
+dvector_assign_op!(Div, DivAssign, div_assign, div_component);
+dvector_assign_op!(Rem, RemAssign, rem_assign, rem_component);
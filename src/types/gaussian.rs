@@ -1,6 +1,7 @@
 use crate::{One, RandNorm};
 use std::ops::{Add, Mul};
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Gaussian2<
     T: RandNorm + One + Clone + Mul<T, Output = T> + Add<T, Output = T> + PartialOrd + Sized,
 > {
@@ -34,6 +35,7 @@ impl<T: RandNorm + One + Clone + Mul<T, Output = T> + Add<T, Output = T> + Parti
     }
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Gaussian3<
     T: RandNorm + One + Clone + Mul<T, Output = T> + Add<T, Output = T> + PartialOrd + Sized,
 > {
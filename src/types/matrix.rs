@@ -67,11 +67,7 @@ impl<T: Sized, const COLUMNS: usize, const ROWS: usize> Matrix<T, COLUMNS, ROWS>
 
     pub fn new_scale(scale: Vector<T, 3>) -> Result<Self, MatrixError>
     where
-        T: Add<T, Output = T> + Sqrt<Output = T> + Clone,
-        T: Mul<T, Output = T>,
-        T: Div<T, Output = T>,
-        Vector<T, ROWS>: Mul<T, Output = Vector<T, ROWS>>,
-        T: Default + One + Clone,
+        T: Real + Default,
     {
         let mut mat = Self::identity();
         mat.set_scale(scale)?;
@@ -92,11 +88,7 @@ impl<T: Sized, const COLUMNS: usize, const ROWS: usize> Matrix<T, COLUMNS, ROWS>
         scale: Vector<T, 3>,
     ) -> Result<Self, MatrixError>
     where
-        T: Add<T, Output = T> + Sqrt<Output = T> + Clone,
-        T: Mul<T, Output = T>,
-        T: Div<T, Output = T>,
-        Vector<T, ROWS>: Mul<T, Output = Vector<T, ROWS>>,
-        T: Default + One + Clone,
+        T: Real + Default,
     {
         let mut mat = Self::identity();
         mat.set_position(position)?;
@@ -120,6 +112,119 @@ impl<T: Sized, const COLUMNS: usize, const ROWS: usize> Matrix<T, COLUMNS, ROWS>
         COLUMNS
     }
 
+    // The column storage as one flat slice in column-major order, i.e. the element at
+    // (column, row) is at column * ROWS + row. This is the layout of to_column_major_array
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { std::slice::from_raw_parts(self.as_ptr(), COLUMNS * ROWS) }
+    }
+
+    // Same column-major slice as as_slice, kept as an alias for existing callers
+    pub fn as_flat_slice(&self) -> &[T] {
+        self.as_slice()
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { std::slice::from_raw_parts_mut(self.as_mut_ptr(), COLUMNS * ROWS) }
+    }
+
+    pub fn as_ptr(&self) -> *const T {
+        self as *const Self as *const T
+    }
+
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self as *mut Self as *mut T
+    }
+
+    pub fn as_bytes(&self) -> &[u8]
+    where
+        T: Pod,
+    {
+        bytes_of(self)
+    }
+
+    // Builds the matrix from elements in column-major order
+    pub fn from_slice(elements: &[T]) -> Result<Self, MatrixError>
+    where
+        T: Clone,
+    {
+        Self::from_column_major_slice(elements)
+    }
+
+    // Builds the matrix from elements in column-major order, i.e. the element at (column, row)
+    // is elements[column * ROWS + row]. This is the layout used by OpenGL and by as_slice
+    pub fn from_column_major_slice(elements: &[T]) -> Result<Self, MatrixError>
+    where
+        T: Clone,
+    {
+        if elements.len() != COLUMNS * ROWS {
+            return Err(MatrixError::IncorrectElementCount);
+        }
+        Ok(Self::from_array(init_array!(
+            [Vector<T, ROWS>; COLUMNS],
+            |column_idx| {
+                Vector::from_slice(&elements[column_idx * ROWS..(column_idx + 1) * ROWS]).unwrap()
+            }
+        )))
+    }
+
+    // Builds the matrix from elements in row-major order, i.e. the element at (column, row) is
+    // elements[row * COLUMNS + column]. Matrices here multiply column vectors (M * v), so data
+    // from APIs that store row-major matrices for row vectors (v * M), such as D3DX, already has
+    // the column-major memory layout and belongs in from_column_major_slice instead
+    pub fn from_row_major_slice(elements: &[T]) -> Result<Self, MatrixError>
+    where
+        T: Clone,
+    {
+        if elements.len() != COLUMNS * ROWS {
+            return Err(MatrixError::IncorrectElementCount);
+        }
+        Ok(Self::from_array(init_array!(
+            [Vector<T, ROWS>; COLUMNS],
+            |column_idx: usize| {
+                Vector::new(init_array!([T; ROWS], |row_idx: usize| elements
+                    [row_idx * COLUMNS + column_idx]
+                    .clone()))
+            }
+        )))
+    }
+
+    // Builds the matrix from its rows, top to bottom
+    pub fn from_rows(rows: [Vector<T, COLUMNS>; ROWS]) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_array(init_array!(
+            [Vector<T, ROWS>; COLUMNS],
+            |column_idx: usize| {
+                Vector::new(init_array!([T; ROWS], |row_idx: usize| rows[row_idx]
+                    [column_idx]
+                    .clone()))
+            }
+        ))
+    }
+
+    // All elements in column-major order, the inverse of from_column_major_slice
+    pub fn to_column_major_array(&self) -> [T; COLUMNS * ROWS]
+    where
+        T: Clone,
+        [(); COLUMNS * ROWS]:,
+    {
+        init_array!([T; COLUMNS * ROWS], |idx: usize| self.columns[idx / ROWS]
+            [idx % ROWS]
+            .clone())
+    }
+
+    // All elements in row-major order, the inverse of from_row_major_slice
+    pub fn to_row_major_array(&self) -> [T; COLUMNS * ROWS]
+    where
+        T: Clone,
+        [(); COLUMNS * ROWS]:,
+    {
+        init_array!([T; COLUMNS * ROWS], |idx: usize| self.columns
+            [idx % COLUMNS][idx / COLUMNS]
+            .clone())
+    }
+
     pub fn column(&self, idx: usize) -> &Vector<T, ROWS> {
         &self.columns[idx]
     }
@@ -137,6 +242,38 @@ impl<T: Sized, const COLUMNS: usize, const ROWS: usize> Matrix<T, COLUMNS, ROWS>
             .clone()))
     }
 
+    pub fn columns(&self) -> std::slice::Iter<'_, Vector<T, ROWS>> {
+        self.columns.iter()
+    }
+
+    pub fn columns_mut(&mut self) -> std::slice::IterMut<'_, Vector<T, ROWS>> {
+        self.columns.iter_mut()
+    }
+
+    // Rows are not contiguous in memory, so they are copied out
+    pub fn rows(&self) -> impl Iterator<Item = Vector<T, COLUMNS>> + '_
+    where
+        T: Clone,
+    {
+        (0..ROWS).map(move |idx| self.row(idx))
+    }
+
+    pub fn set_row(&mut self, idx: usize, row: Vector<T, COLUMNS>) {
+        for (column, value) in self.columns_mut().zip(row) {
+            column[idx] = value;
+        }
+    }
+
+    pub fn swap_rows(&mut self, a: usize, b: usize) {
+        for column in self.columns_mut() {
+            column.components.swap(a, b);
+        }
+    }
+
+    pub fn swap_columns(&mut self, a: usize, b: usize) {
+        self.columns.components.swap(a, b);
+    }
+
     fn _row_with_column_size(&self, idx: usize) -> Vector<T, ROWS>
     where
         T: Clone,
@@ -176,17 +313,21 @@ impl<T: Sized, const COLUMNS: usize, const ROWS: usize> Matrix<T, COLUMNS, ROWS>
         self.columns -= &other.columns;
     }
 
-    pub fn mul_matrix(&self, other: &Matrix<T, ROWS, COLUMNS>) -> Matrix<T, ROWS, COLUMNS>
+    pub fn mul_matrix<const C: usize>(&self, other: &Matrix<T, C, COLUMNS>) -> Matrix<T, C, ROWS>
     where
         T: Mul<T, Output = T> + Clone + Sum,
     {
-        let columns = Vector::new(init_array!([Vector<T, COLUMNS>; ROWS], |row_idx| {
-            Vector::new(init_array!([T; COLUMNS], |column_idx| {
-                let column = other.row(column_idx);
-                self.column(row_idx).dot(&column)
-            }))
-        }));
-        Matrix::new(columns)
+        Matrix::from_array(init_array!([Vector<T, ROWS>; C], |column_idx| self
+            .mul_vector(other.column(column_idx))))
+    }
+
+    pub fn mul_vector(&self, vector: &Vector<T, COLUMNS>) -> Vector<T, ROWS>
+    where
+        T: Mul<T, Output = T> + Clone + Sum,
+    {
+        Vector::new(init_array!([T; ROWS], |row_idx| self
+            .row(row_idx)
+            .dot(vector)))
     }
 
     pub fn div_matrix(&mut self, other: &Self)
@@ -234,10 +375,7 @@ impl<T: Sized, const COLUMNS: usize, const ROWS: usize> Matrix<T, COLUMNS, ROWS>
 
     pub fn set_scale(&mut self, scale: Vector<T, 3>) -> Result<(), MatrixError>
     where
-        T: Add<T, Output = T> + Sqrt<Output = T> + Clone,
-        T: Mul<T, Output = T>,
-        T: Div<T, Output = T>,
-        Vector<T, ROWS>: Mul<T, Output = Vector<T, ROWS>>,
+        T: Real,
     {
         if ROWS < 3 {
             return Err(MatrixError::TooFewRows);
@@ -248,21 +386,21 @@ impl<T: Sized, const COLUMNS: usize, const ROWS: usize> Matrix<T, COLUMNS, ROWS>
         self.columns[0] = self.columns[0]
             .normalized()
             .map_err(MatrixError::VectorError)?
-            * scale[0].clone();
+            * scale[0];
         self.columns[1] = self.columns[1]
             .normalized()
             .map_err(MatrixError::VectorError)?
-            * scale[1].clone();
+            * scale[1];
         self.columns[2] = self.columns[2]
             .normalized()
             .map_err(MatrixError::VectorError)?
-            * scale[2].clone();
+            * scale[2];
         Ok(())
     }
 
     pub fn scale(&self) -> Result<Vector<T, 3>, MatrixError>
     where
-        T: Add<T, Output = T> + Mul<T, Output = T> + Sqrt<Output = T> + Clone,
+        T: Real,
     {
         if ROWS < 3 {
             return Err(MatrixError::TooFewRows);
@@ -364,14 +502,6 @@ impl<T: Sized, const COLUMNS: usize, const ROWS: usize> Matrix<T, COLUMNS, ROWS>
         ))
     }
 
-    pub fn transform_point(&self, point: Vector<T, 3>) -> Result<Vector<T, 3>, MatrixError>
-    where
-        T: Mul<T, Output = T> + Clone + Sum + Default + One,
-    {
-        let b = Matrix::new_position(point)?;
-        self.mul_matrix(&b).position()
-    }
-
     pub fn transposed(&self) -> Result<Self, MatrixError>
     where
         T: Clone,
@@ -388,6 +518,24 @@ impl<T: Sized, const COLUMNS: usize, const ROWS: usize> Matrix<T, COLUMNS, ROWS>
         )))
     }
 
+    // Keeps the upper-left block; new cells are filled from the identity matrix
+    pub fn resize<const C: usize, const R: usize>(&self) -> Matrix<T, C, R>
+    where
+        T: Clone + Zero + One,
+    {
+        Matrix::new(Vector::new(init_array!([Vector<T, R>; C], |column_idx| {
+            Vector::new(init_array!([T; R], |row_idx| {
+                if column_idx < COLUMNS && row_idx < ROWS {
+                    self[column_idx][row_idx].clone()
+                } else if column_idx == row_idx {
+                    T::one()
+                } else {
+                    T::zero()
+                }
+            }))
+        })))
+    }
+
     pub fn convert<T2: Sized>(&self) -> Matrix<T2, COLUMNS, ROWS>
     where
         T: Into<T2> + Clone,
@@ -401,6 +549,276 @@ impl<T: Sized, const COLUMNS: usize, const ROWS: usize> Matrix<T, COLUMNS, ROWS>
     }
 }
 
+impl<T: Sized> Matrix<T, 4, 4> {
+    pub fn transform_point(&self, point: Vector<T, 3>) -> Vector<T, 3>
+    where
+        T: Mul<T, Output = T> + Clone + Sum + One,
+    {
+        self.mul_vector(&point.to_homogeneous_point()).xyz()
+    }
+
+    pub fn transform_vector(&self, vector: Vector<T, 3>) -> Vector<T, 3>
+    where
+        T: Mul<T, Output = T> + Clone + Sum + Zero,
+    {
+        self.mul_vector(&vector.to_homogeneous_direction()).xyz()
+    }
+
+    pub fn transform_homogeneous(&self, point: Vector<T, 3>) -> Vector<T, 3>
+    where
+        T: Mul<T, Output = T> + Div<T, Output = T> + Clone + Sum + One,
+    {
+        let transformed = self.mul_vector(&point.to_homogeneous_point());
+        transformed.xyz() / transformed.w().clone()
+    }
+}
+
+// Translation, rotation and scale
+pub type Trs<T> = (Vector<T, 3>, Quaternion<T>, Vector<T, 3>);
+
+impl<T> Matrix<T, 4, 4>
+where
+    T: Real,
+{
+    // Built from the TransformMatrix rotation, with its axes scaled and the translation placed
+    // in the last column
+    pub fn from_trs(
+        translation: Vector<T, 3>,
+        rotation: Quaternion<T>,
+        scale: Vector<T, 3>,
+    ) -> Self {
+        // A zero quaternion has no rotation to normalize, and collapses the axes like a zero
+        // scale would
+        let rotation = rotation.normalized().unwrap_or(rotation);
+        let mut matrix = Self::from(rotation);
+        for idx in 0..3 {
+            matrix[idx] *= scale[idx];
+        }
+        matrix[3] = translation.extend(T::one());
+        matrix
+    }
+
+    pub fn decompose(&self) -> Result<Trs<T>, MatrixError> {
+        let translation = self.position()?;
+        let (x, y, z) = (self.x()?, self.y()?, self.z()?);
+        let scale = self.scale()?;
+        let tolerance = zero_tolerance(scale[0].max(&scale[1]).max(&scale[2]));
+        if scale.iter().any(|axis| *axis <= tolerance) {
+            return Err(MatrixError::Singular);
+        }
+        if x.cross(&y).dot(&z) < T::zero() {
+            return Err(MatrixError::NegativeScale);
+        }
+        let rotation = Quaternion::from_rotation_matrix(&Matrix::from_array([
+            x / scale[0],
+            y / scale[1],
+            z / scale[2],
+        ]));
+        Ok((translation, rotation, scale))
+    }
+}
+
+impl<T: Sized, const N: usize> Vector<T, N> {
+    pub fn mul_matrix<const COLUMNS: usize>(
+        &self,
+        matrix: &Matrix<T, COLUMNS, N>,
+    ) -> Vector<T, COLUMNS>
+    where
+        T: Mul<T, Output = T> + Clone + Sum,
+    {
+        Vector::new(init_array!([T; COLUMNS], |column_idx| self
+            .dot(matrix.column(column_idx))))
+    }
+}
+
+impl<T: Sized, const N: usize> Matrix<T, N, N> {
+    pub fn determinant(&self) -> T
+    where
+        T: Signed,
+    {
+        let a = |column_idx: usize, row_idx: usize| self.columns[column_idx][row_idx].clone();
+        match N {
+            2 => a(0, 0) * a(1, 1) - a(1, 0) * a(0, 1),
+            3 => {
+                a(0, 0) * (a(1, 1) * a(2, 2) - a(1, 2) * a(2, 1))
+                    - a(0, 1) * (a(1, 0) * a(2, 2) - a(1, 2) * a(2, 0))
+                    + a(0, 2) * (a(1, 0) * a(2, 1) - a(1, 1) * a(2, 0))
+            }
+            4 => {
+                let (s, c) = self.sub_determinants_4x4();
+                s[0].clone() * c[5].clone() - s[1].clone() * c[4].clone()
+                    + s[2].clone() * c[3].clone()
+                    + s[3].clone() * c[2].clone()
+                    - s[4].clone() * c[1].clone()
+                    + s[5].clone() * c[0].clone()
+            }
+            _ => self.determinant_bareiss(),
+        }
+    }
+
+    // Fraction-free (Bareiss) elimination with partial pivoting. Every division is exact, so
+    // integer determinants come out exact as well
+    fn determinant_bareiss(&self) -> T
+    where
+        T: Signed,
+    {
+        let mut a = self.clone();
+        let mut negate = false;
+        let mut previous = T::one();
+        for k in 0..N {
+            let mut pivot_idx = k;
+            for row_idx in (k + 1)..N {
+                if a.columns[k][row_idx].abs() > a.columns[k][pivot_idx].abs() {
+                    pivot_idx = row_idx;
+                }
+            }
+            if a.columns[k][pivot_idx] == T::zero() {
+                return T::zero();
+            }
+            if pivot_idx != k {
+                for column_idx in k..N {
+                    a.columns[column_idx].components.swap(k, pivot_idx);
+                }
+                negate = !negate;
+            }
+            for row_idx in (k + 1)..N {
+                for column_idx in (k + 1)..N {
+                    a.columns[column_idx][row_idx] = (a.columns[column_idx][row_idx].clone()
+                        * a.columns[k][k].clone()
+                        - a.columns[k][row_idx].clone() * a.columns[column_idx][k].clone())
+                        / previous.clone();
+                }
+            }
+            previous = a.columns[k][k].clone();
+        }
+        if negate {
+            -previous
+        } else {
+            previous
+        }
+    }
+
+    // Integer matrices have no integer inverse in general, so this needs a Real type
+    pub fn inverse(&self) -> Result<Self, MatrixError>
+    where
+        T: Real,
+    {
+        match N {
+            2..=4 => self.inverse_closed_form(),
+            _ => self.inverse_lu(),
+        }
+    }
+
+    fn inverse_closed_form(&self) -> Result<Self, MatrixError>
+    where
+        T: Real,
+    {
+        let det = self.determinant();
+        if det == T::zero() {
+            return Err(MatrixError::Singular);
+        }
+        let inv_det = T::one() / det;
+
+        // The formulas below are written for row-major indices, but since the inverse of the
+        // transpose is the transpose of the inverse they can be applied to columns directly
+        let a = |column_idx: usize, row_idx: usize| self.columns[column_idx][row_idx];
+        let b: [T; 16] = match N {
+            2 => pad_to_16([a(1, 1), -a(0, 1), -a(1, 0), a(0, 0)]),
+            3 => pad_to_16([
+                a(1, 1) * a(2, 2) - a(1, 2) * a(2, 1),
+                a(0, 2) * a(2, 1) - a(0, 1) * a(2, 2),
+                a(0, 1) * a(1, 2) - a(0, 2) * a(1, 1),
+                a(1, 2) * a(2, 0) - a(1, 0) * a(2, 2),
+                a(0, 0) * a(2, 2) - a(0, 2) * a(2, 0),
+                a(0, 2) * a(1, 0) - a(0, 0) * a(1, 2),
+                a(1, 0) * a(2, 1) - a(1, 1) * a(2, 0),
+                a(0, 1) * a(2, 0) - a(0, 0) * a(2, 1),
+                a(0, 0) * a(1, 1) - a(0, 1) * a(1, 0),
+            ]),
+            _ => {
+                let (s, c) = self.sub_determinants_4x4();
+                let s = |idx: usize| s[idx];
+                let c = |idx: usize| c[idx];
+                [
+                    a(1, 1) * c(5) - a(1, 2) * c(4) + a(1, 3) * c(3),
+                    a(0, 2) * c(4) - a(0, 1) * c(5) - a(0, 3) * c(3),
+                    a(3, 1) * s(5) - a(3, 2) * s(4) + a(3, 3) * s(3),
+                    a(2, 2) * s(4) - a(2, 1) * s(5) - a(2, 3) * s(3),
+                    a(1, 2) * c(2) - a(1, 0) * c(5) - a(1, 3) * c(1),
+                    a(0, 0) * c(5) - a(0, 2) * c(2) + a(0, 3) * c(1),
+                    a(3, 2) * s(2) - a(3, 0) * s(5) - a(3, 3) * s(1),
+                    a(2, 0) * s(5) - a(2, 2) * s(2) + a(2, 3) * s(1),
+                    a(1, 0) * c(4) - a(1, 1) * c(2) + a(1, 3) * c(0),
+                    a(0, 1) * c(2) - a(0, 0) * c(4) - a(0, 3) * c(0),
+                    a(3, 0) * s(4) - a(3, 1) * s(2) + a(3, 3) * s(0),
+                    a(2, 1) * s(2) - a(2, 0) * s(4) - a(2, 3) * s(0),
+                    a(1, 1) * c(1) - a(1, 0) * c(3) - a(1, 2) * c(0),
+                    a(0, 0) * c(3) - a(0, 1) * c(1) + a(0, 2) * c(0),
+                    a(3, 1) * s(1) - a(3, 0) * s(3) - a(3, 2) * s(0),
+                    a(2, 0) * s(3) - a(2, 1) * s(1) + a(2, 2) * s(0),
+                ]
+            }
+        };
+
+        Ok(Self::new(Vector::new(init_array!(
+            [Vector<T, N>; N],
+            |column_idx| Vector::new(init_array!([T; N], |row_idx| {
+                b[column_idx * N + row_idx] * inv_det
+            }))
+        ))))
+    }
+
+    fn sub_determinants_4x4(&self) -> ([T; 6], [T; 6])
+    where
+        T: Sub<T, Output = T> + Mul<T, Output = T> + Clone,
+    {
+        let a = |column_idx: usize, row_idx: usize| self.columns[column_idx][row_idx].clone();
+        (
+            [
+                a(0, 0) * a(1, 1) - a(1, 0) * a(0, 1),
+                a(0, 0) * a(1, 2) - a(1, 0) * a(0, 2),
+                a(0, 0) * a(1, 3) - a(1, 0) * a(0, 3),
+                a(0, 1) * a(1, 2) - a(1, 1) * a(0, 2),
+                a(0, 1) * a(1, 3) - a(1, 1) * a(0, 3),
+                a(0, 2) * a(1, 3) - a(1, 2) * a(0, 3),
+            ],
+            [
+                a(2, 0) * a(3, 1) - a(3, 0) * a(2, 1),
+                a(2, 0) * a(3, 2) - a(3, 0) * a(2, 2),
+                a(2, 0) * a(3, 3) - a(3, 0) * a(2, 3),
+                a(2, 1) * a(3, 2) - a(3, 1) * a(2, 2),
+                a(2, 1) * a(3, 3) - a(3, 1) * a(2, 3),
+                a(2, 2) * a(3, 3) - a(3, 2) * a(2, 3),
+            ],
+        )
+    }
+
+    fn inverse_lu(&self) -> Result<Self, MatrixError>
+    where
+        T: Real,
+    {
+        self.lu()?.solve_matrix(&identity())
+    }
+
+    // LU decomposition with partial pivoting. L and U are packed into one matrix (L has an
+    // implicit unit diagonal), and the row permutation and swap parity are returned with it
+    pub(crate) fn lu_decomposed(&self) -> Option<(Self, [usize; N], bool)>
+    where
+        T: Real,
+    {
+        let mut lu = *self;
+        let mut permutation = init_array!([usize; N], |idx| idx);
+        let odd_swaps = lu_factorize(&mut lu, &mut permutation)?;
+        Some((lu, permutation, odd_swaps))
+    }
+}
+
+// Fills the closed-form inverse buffer, which is sized for the largest (4x4) case
+fn pad_to_16<T: Zero, const M: usize>(values: [T; M]) -> [T; 16] {
+    let mut values = std::array::IntoIter::new(values);
+    init_array!([T; 16], mut |_| values.next().unwrap_or_else(T::zero))
+}
+
 impl<T: Sized, const COLUMNS: usize, const ROWS: usize> PartialEq for Matrix<T, COLUMNS, ROWS>
 where
     Vector<Vector<T, ROWS>, COLUMNS>: PartialEq,
@@ -415,6 +833,38 @@ impl<T: Sized, const COLUMNS: usize, const ROWS: usize> Eq for Matrix<T, COLUMNS
 {
 }
 
+impl<T: Sized, const COLUMNS: usize, const ROWS: usize> ApproxEq for Matrix<T, COLUMNS, ROWS>
+where
+    T: ApproxEq,
+{
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> T::Epsilon {
+        T::default_epsilon()
+    }
+
+    fn default_max_relative() -> T::Epsilon {
+        T::default_max_relative()
+    }
+
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: T::Epsilon) -> bool {
+        self.columns.abs_diff_eq(&other.columns, epsilon)
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: T::Epsilon, max_relative: T::Epsilon) -> bool {
+        self.columns
+            .relative_eq(&other.columns, epsilon, max_relative)
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+        self.columns.ulps_eq(&other.columns, epsilon, max_ulps)
+    }
+}
+
 impl<T: Sized, const COLUMNS: usize, const ROWS: usize> Clone for Matrix<T, COLUMNS, ROWS>
 where
     Vector<Vector<T, ROWS>, COLUMNS>: Clone,
@@ -431,6 +881,18 @@ impl<T: Sized, const COLUMNS: usize, const ROWS: usize> Copy for Matrix<T, COLUM
 {
 }
 
+unsafe impl<T: Zeroable, const COLUMNS: usize, const ROWS: usize> Zeroable
+    for Matrix<T, COLUMNS, ROWS>
+{
+}
+unsafe impl<T: Pod, const COLUMNS: usize, const ROWS: usize> Pod for Matrix<T, COLUMNS, ROWS> {}
+
+crate::assert_layout!(Matrix<f32, 2, 2>, size: 16, align: 4);
+crate::assert_layout!(Matrix<f32, 3, 3>, size: 36, align: 4);
+crate::assert_layout!(Matrix<f32, 4, 4>, size: 64, align: 4);
+crate::assert_layout!(Matrix<f64, 4, 4>, size: 128, align: 8);
+crate::assert_layout!(Matrix<f32, 4, 3>, size: 48, align: 4);
+
 impl<T: Sized, const COLUMNS: usize, const ROWS: usize> Debug for Matrix<T, COLUMNS, ROWS>
 where
     Vector<Vector<T, ROWS>, COLUMNS>: Debug,
@@ -460,50 +922,50 @@ impl<T: Sized, const COLUMNS: usize, const ROWS: usize> IndexMut<usize>
 
 macro_rules! matrix_binary_op_mul {
     ($op:ident, $fn_name:ident, $type_method:ident) => {
-        impl<'a, 'b, T: Sized, const COLUMNS: usize, const ROWS: usize>
-            $op<&'b Matrix<T, ROWS, COLUMNS>> for &'a Matrix<T, COLUMNS, ROWS>
+        impl<'a, 'b, T: Sized, const C: usize, const K: usize, const R: usize>
+            $op<&'b Matrix<T, C, K>> for &'a Matrix<T, K, R>
         where
             T: $op<T, Output = T> + Clone + Sum,
         {
-            type Output = Matrix<T, ROWS, COLUMNS>;
+            type Output = Matrix<T, C, R>;
 
-            fn $fn_name(self, rhs: &'b Matrix<T, ROWS, COLUMNS>) -> Matrix<T, ROWS, COLUMNS> {
+            fn $fn_name(self, rhs: &'b Matrix<T, C, K>) -> Matrix<T, C, R> {
                 self.$type_method(rhs)
             }
         }
 
-        impl<'a, T: Sized, const COLUMNS: usize, const ROWS: usize>
-            $op<&'a Matrix<T, ROWS, COLUMNS>> for Matrix<T, COLUMNS, ROWS>
+        impl<'a, T: Sized, const C: usize, const K: usize, const R: usize> $op<&'a Matrix<T, C, K>>
+            for Matrix<T, K, R>
         where
             T: $op<T, Output = T> + Clone + Sum,
         {
-            type Output = Matrix<T, ROWS, COLUMNS>;
+            type Output = Matrix<T, C, R>;
 
-            fn $fn_name(self, rhs: &'a Matrix<T, ROWS, COLUMNS>) -> Matrix<T, ROWS, COLUMNS> {
+            fn $fn_name(self, rhs: &'a Matrix<T, C, K>) -> Matrix<T, C, R> {
                 self.$type_method(rhs)
             }
         }
 
-        impl<'a, T: Sized, const COLUMNS: usize, const ROWS: usize> $op<Matrix<T, ROWS, COLUMNS>>
-            for &'a Matrix<T, COLUMNS, ROWS>
+        impl<'a, T: Sized, const C: usize, const K: usize, const R: usize> $op<Matrix<T, C, K>>
+            for &'a Matrix<T, K, R>
         where
             T: $op<T, Output = T> + Clone + Sum,
         {
-            type Output = Matrix<T, ROWS, COLUMNS>;
+            type Output = Matrix<T, C, R>;
 
-            fn $fn_name(self, rhs: Matrix<T, ROWS, COLUMNS>) -> Matrix<T, ROWS, COLUMNS> {
+            fn $fn_name(self, rhs: Matrix<T, C, K>) -> Matrix<T, C, R> {
                 self.$type_method(&rhs)
             }
         }
 
-        impl<T: Sized, const COLUMNS: usize, const ROWS: usize> $op<Matrix<T, ROWS, COLUMNS>>
-            for Matrix<T, COLUMNS, ROWS>
+        impl<T: Sized, const C: usize, const K: usize, const R: usize> $op<Matrix<T, C, K>>
+            for Matrix<T, K, R>
         where
             T: $op<T, Output = T> + Clone + Sum,
         {
-            type Output = Matrix<T, ROWS, COLUMNS>;
+            type Output = Matrix<T, C, R>;
 
-            fn $fn_name(self, rhs: Matrix<T, ROWS, COLUMNS>) -> Matrix<T, ROWS, COLUMNS> {
+            fn $fn_name(self, rhs: Matrix<T, C, K>) -> Matrix<T, C, R> {
                 self.$type_method(&rhs)
             }
         }
@@ -574,6 +1036,102 @@ macro_rules! matrix_binary_op {
     };
 }
 
+impl<T: Sized, const COLUMNS: usize, const ROWS: usize> Mul<&Vector<T, COLUMNS>>
+    for &Matrix<T, COLUMNS, ROWS>
+where
+    T: Mul<T, Output = T> + Clone + Sum,
+{
+    type Output = Vector<T, ROWS>;
+
+    fn mul(self, rhs: &Vector<T, COLUMNS>) -> Vector<T, ROWS> {
+        self.mul_vector(rhs)
+    }
+}
+
+impl<T: Sized, const COLUMNS: usize, const ROWS: usize> Mul<&Vector<T, COLUMNS>>
+    for Matrix<T, COLUMNS, ROWS>
+where
+    T: Mul<T, Output = T> + Clone + Sum,
+{
+    type Output = Vector<T, ROWS>;
+
+    fn mul(self, rhs: &Vector<T, COLUMNS>) -> Vector<T, ROWS> {
+        self.mul_vector(rhs)
+    }
+}
+
+impl<T: Sized, const COLUMNS: usize, const ROWS: usize> Mul<Vector<T, COLUMNS>>
+    for &Matrix<T, COLUMNS, ROWS>
+where
+    T: Mul<T, Output = T> + Clone + Sum,
+{
+    type Output = Vector<T, ROWS>;
+
+    fn mul(self, rhs: Vector<T, COLUMNS>) -> Vector<T, ROWS> {
+        self.mul_vector(&rhs)
+    }
+}
+
+impl<T: Sized, const COLUMNS: usize, const ROWS: usize> Mul<Vector<T, COLUMNS>>
+    for Matrix<T, COLUMNS, ROWS>
+where
+    T: Mul<T, Output = T> + Clone + Sum,
+{
+    type Output = Vector<T, ROWS>;
+
+    fn mul(self, rhs: Vector<T, COLUMNS>) -> Vector<T, ROWS> {
+        self.mul_vector(&rhs)
+    }
+}
+
+impl<T: Sized, const COLUMNS: usize, const ROWS: usize> Mul<&Matrix<T, COLUMNS, ROWS>>
+    for &Vector<T, ROWS>
+where
+    T: Mul<T, Output = T> + Clone + Sum,
+{
+    type Output = Vector<T, COLUMNS>;
+
+    fn mul(self, rhs: &Matrix<T, COLUMNS, ROWS>) -> Vector<T, COLUMNS> {
+        self.mul_matrix(rhs)
+    }
+}
+
+impl<T: Sized, const COLUMNS: usize, const ROWS: usize> Mul<&Matrix<T, COLUMNS, ROWS>>
+    for Vector<T, ROWS>
+where
+    T: Mul<T, Output = T> + Clone + Sum,
+{
+    type Output = Vector<T, COLUMNS>;
+
+    fn mul(self, rhs: &Matrix<T, COLUMNS, ROWS>) -> Vector<T, COLUMNS> {
+        self.mul_matrix(rhs)
+    }
+}
+
+impl<T: Sized, const COLUMNS: usize, const ROWS: usize> Mul<Matrix<T, COLUMNS, ROWS>>
+    for &Vector<T, ROWS>
+where
+    T: Mul<T, Output = T> + Clone + Sum,
+{
+    type Output = Vector<T, COLUMNS>;
+
+    fn mul(self, rhs: Matrix<T, COLUMNS, ROWS>) -> Vector<T, COLUMNS> {
+        self.mul_matrix(&rhs)
+    }
+}
+
+impl<T: Sized, const COLUMNS: usize, const ROWS: usize> Mul<Matrix<T, COLUMNS, ROWS>>
+    for Vector<T, ROWS>
+where
+    T: Mul<T, Output = T> + Clone + Sum,
+{
+    type Output = Vector<T, COLUMNS>;
+
+    fn mul(self, rhs: Matrix<T, COLUMNS, ROWS>) -> Vector<T, COLUMNS> {
+        self.mul_matrix(&rhs)
+    }
+}
+
 matrix_binary_op!(Add, add, add_matrix);
 matrix_binary_op!(Sub, sub, sub_matrix);
 matrix_binary_op_mul!(Mul, mul, mul_matrix);
@@ -581,53 +1139,136 @@ matrix_binary_op!(Div, div, div_matrix);
 matrix_binary_op!(Rem, rem, rem_matrix);
 
 pub trait TransformMatrix<T>: Sized {
-    fn new_rotation(quat: &Quaternion) -> Result<Self, MatrixError>;
+    fn new_rotation(quat: &Quaternion<T>) -> Result<Self, MatrixError>;
     fn new_rotation_on_axis(axis: Vector<T, 3>, radians: T) -> Result<Self, MatrixError>;
     fn view(from: Vector<T, 3>, to: Vector<T, 3>, up: Vector<T, 3>) -> Result<Self, MatrixError>;
     fn ortho(size: Vector<T, 2>, near: T, far: T) -> Self;
     fn projection(fov: T, aspect: T, near_plane: T, far_plane: T) -> Result<Self, MatrixError>;
+    fn projection_with_config(
+        fov: T,
+        aspect: T,
+        near_plane: T,
+        far_plane: T,
+        config: &ProjectionConfig,
+    ) -> Result<Self, MatrixError>;
+    fn frustum(
+        left: T,
+        right: T,
+        bottom: T,
+        top: T,
+        near_plane: T,
+        far_plane: T,
+        config: &ProjectionConfig,
+    ) -> Result<Self, MatrixError>;
+    fn ortho_off_center(
+        left: T,
+        right: T,
+        bottom: T,
+        top: T,
+        near: T,
+        far: T,
+        config: &ProjectionConfig,
+    ) -> Result<Self, MatrixError>;
 }
 
-impl TransformMatrix<f32> for Matrix<f32, 4, 4> {
-    fn new_rotation(quat: &Quaternion) -> Result<Self, MatrixError> {
-        let (axis, angle) = quat.axis_angle();
-        Self::new_rotation_on_axis(axis, angle)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Handedness {
+    LeftHanded,
+    RightHanded,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DepthRange {
+    ZeroToOne,
+    NegativeOneToOne,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProjectionConfig {
+    pub handedness: Handedness,
+    pub depth_range: DepthRange,
+    // Maps the near plane to 1 and the far plane to the low end of the depth range
+    pub reversed_z: bool,
+    // Ignores the far plane. Only perspective projections support this, orthographic ones
+    // return IncorrectNearFarPlanes
+    pub infinite_far: bool,
+    pub flip_y: bool,
+}
+
+impl ProjectionConfig {
+    pub const OPENGL: Self = Self {
+        handedness: Handedness::RightHanded,
+        depth_range: DepthRange::NegativeOneToOne,
+        reversed_z: false,
+        infinite_far: false,
+        flip_y: false,
+    };
+
+    pub const VULKAN: Self = Self {
+        handedness: Handedness::RightHanded,
+        depth_range: DepthRange::ZeroToOne,
+        reversed_z: false,
+        infinite_far: false,
+        flip_y: true,
+    };
+
+    pub const DIRECTX: Self = Self {
+        handedness: Handedness::LeftHanded,
+        depth_range: DepthRange::ZeroToOne,
+        reversed_z: false,
+        infinite_far: false,
+        flip_y: false,
+    };
+}
+
+impl Default for ProjectionConfig {
+    fn default() -> Self {
+        Self {
+            handedness: Handedness::RightHanded,
+            depth_range: DepthRange::ZeroToOne,
+            reversed_z: false,
+            infinite_far: false,
+            flip_y: false,
+        }
     }
+}
 
-    fn new_rotation_on_axis(axis: Vector<f32, 3>, radians: f32) -> Result<Self, MatrixError> {
-        let axis = axis
-            .normalized()
-            .map_err(MatrixError::VectorError)?;
+impl<T: Real> TransformMatrix<T> for Matrix<T, 4, 4> {
+    // Converted directly rather than through axis_angle, which drops very small rotations. The
+    // quaternion is normalized first since the conversion skews the axes otherwise
+    fn new_rotation(quat: &Quaternion<T>) -> Result<Self, MatrixError> {
+        let quat = quat.normalized().map_err(MatrixError::QuaternionError)?;
+        Ok(Self::from(quat))
+    }
+
+    fn new_rotation_on_axis(axis: Vector<T, 3>, radians: T) -> Result<Self, MatrixError> {
+        let axis = axis.normalized().map_err(MatrixError::VectorError)?;
         let (sin, cos) = (-radians).sin_cos();
-        let t = 1.0 - cos;
+        let t = T::one() - cos;
         Ok(Matrix::new(vector!(
             vector!(
                 t * axis[0] * axis[0] + cos,
                 t * axis[0] * axis[1] - sin * axis[2],
                 t * axis[0] * axis[2] + sin * axis[1],
-                0.0,
+                T::zero(),
             ),
             vector!(
                 t * axis[0] * axis[1] + sin * axis[2],
                 t * axis[1] * axis[1] + cos,
                 t * axis[1] * axis[2] - sin * axis[0],
-                0.0,
+                T::zero(),
             ),
             vector!(
                 t * axis[0] * axis[2] - sin * axis[1],
                 t * axis[1] * axis[2] + sin * axis[0],
                 t * axis[2] * axis[2] + cos,
-                0.0,
+                T::zero(),
             ),
-            vector!(0.0, 0.0, 0.0, 1.0),
+            vector!(T::zero(), T::zero(), T::zero(), T::one()),
         )))
     }
 
-    fn view(
-        from: Vector<f32, 3>,
-        to: Vector<f32, 3>,
-        up: Vector<f32, 3>,
-    ) -> Result<Self, MatrixError> {
+    fn view(from: Vector<T, 3>, to: Vector<T, 3>, up: Vector<T, 3>) -> Result<Self, MatrixError> {
         let zaxis = (from - to).normalized().map_err(MatrixError::VectorError)?;
         let xaxis = up
             .cross(&zaxis)
@@ -636,64 +1277,199 @@ impl TransformMatrix<f32> for Matrix<f32, 4, 4> {
         let yaxis = zaxis.cross(&xaxis);
 
         Ok(Self::new(vector!(
-            vector!(xaxis[0], yaxis[0], zaxis[0], 0.0),
-            vector!(xaxis[1], yaxis[1], zaxis[1], 0.0),
-            vector!(xaxis[2], yaxis[2], zaxis[2], 0.0),
-            vector!(-xaxis.dot(&from), -yaxis.dot(&from), -zaxis.dot(&from), 1.0),
+            vector!(xaxis[0], yaxis[0], zaxis[0], T::zero()),
+            vector!(xaxis[1], yaxis[1], zaxis[1], T::zero()),
+            vector!(xaxis[2], yaxis[2], zaxis[2], T::zero()),
+            vector!(
+                -xaxis.dot(&from),
+                -yaxis.dot(&from),
+                -zaxis.dot(&from),
+                T::one()
+            ),
         )))
     }
 
-    fn ortho(size: Vector<f32, 2>, near: f32, far: f32) -> Self {
+    fn ortho(size: Vector<T, 2>, near: T, far: T) -> Self {
         Self::new(vector!(
-            vector!(2.0 / size[0], 0.0, 0.0, 0.0),
-            vector!(0.0, 2.0 / size[1], 0.0, 0.0),
-            vector!(0.0, 0.0, 1.0 / (near - far), 0.0),
-            vector!(0.0, 0.0, near / (near - far), 1.0),
+            vector!(T::two() / size[0], T::zero(), T::zero(), T::zero()),
+            vector!(T::zero(), T::two() / size[1], T::zero(), T::zero()),
+            vector!(T::zero(), T::zero(), T::one() / (near - far), T::zero()),
+            vector!(T::zero(), T::zero(), near / (near - far), T::one()),
         ))
     }
 
-    fn projection(
-        fov: f32,
-        aspect: f32,
-        near_plane: f32,
-        far_plane: f32,
-    ) -> Result<Self, MatrixError> {
-        if fov <= 0.0 || fov >= std::f32::consts::PI {
+    fn projection(fov: T, aspect: T, near_plane: T, far_plane: T) -> Result<Self, MatrixError> {
+        if fov <= T::zero() || fov >= T::pi() {
             return Err(MatrixError::OutOfRangeFOV);
         }
-        if near_plane <= 0.0 || near_plane >= far_plane {
+        if near_plane <= T::zero() || near_plane >= far_plane {
             return Err(MatrixError::IncorrectNearFarPlanes);
         }
 
-        let y_scale = 1.0 / (fov * 0.5).tan();
+        let y_scale = T::one() / (fov / T::two()).tan();
         let x_scale = y_scale / aspect;
 
         Ok(Self::new(vector!(
-            vector!(x_scale, 0.0, 0.0, 0.0),
-            vector!(0.0, y_scale, 0.0, 0.0),
-            vector!(0.0, 0.0, far_plane / (near_plane - far_plane), -1.0),
+            vector!(x_scale, T::zero(), T::zero(), T::zero()),
+            vector!(T::zero(), y_scale, T::zero(), T::zero()),
+            vector!(
+                T::zero(),
+                T::zero(),
+                far_plane / (near_plane - far_plane),
+                -T::one()
+            ),
             vector!(
-                0.0,
-                0.0,
+                T::zero(),
+                T::zero(),
                 near_plane * far_plane / (near_plane - far_plane),
-                0.0,
+                T::zero(),
+            ),
+        )))
+    }
+
+    fn projection_with_config(
+        fov: T,
+        aspect: T,
+        near_plane: T,
+        far_plane: T,
+        config: &ProjectionConfig,
+    ) -> Result<Self, MatrixError> {
+        if fov <= T::zero() || fov >= T::pi() {
+            return Err(MatrixError::OutOfRangeFOV);
+        }
+        let top = near_plane * (fov / T::two()).tan();
+        let right = top * aspect;
+        Self::frustum(-right, right, -top, top, near_plane, far_plane, config)
+    }
+
+    fn frustum(
+        left: T,
+        right: T,
+        bottom: T,
+        top: T,
+        near_plane: T,
+        far_plane: T,
+        config: &ProjectionConfig,
+    ) -> Result<Self, MatrixError> {
+        if near_plane <= T::zero() || (!config.infinite_far && near_plane >= far_plane) {
+            return Err(MatrixError::IncorrectNearFarPlanes);
+        }
+        if left == right || bottom == top {
+            return Err(MatrixError::IncorrectFrustumBounds);
+        }
+
+        // Depth along the view direction is `sign * z`, which becomes w
+        let sign = match config.handedness {
+            Handedness::LeftHanded => T::one(),
+            Handedness::RightHanded => -T::one(),
+        };
+        let y_sign = if config.flip_y { -T::one() } else { T::one() };
+        let (near_depth, far_depth) = depth_targets::<T>(config);
+
+        // Solve depth * a + b = (near_depth, far_depth) * depth at the near and far planes
+        let (a, b) = if config.infinite_far {
+            (far_depth, (near_depth - far_depth) * near_plane)
+        } else {
+            let b = (near_depth - far_depth) * near_plane * far_plane / (far_plane - near_plane);
+            (far_depth - b / far_plane, b)
+        };
+
+        let width = right - left;
+        let height = top - bottom;
+        Ok(Self::new(vector!(
+            vector!(
+                T::two() * near_plane / width,
+                T::zero(),
+                T::zero(),
+                T::zero()
+            ),
+            vector!(
+                T::zero(),
+                y_sign * T::two() * near_plane / height,
+                T::zero(),
+                T::zero()
+            ),
+            vector!(
+                -sign * (right + left) / width,
+                -sign * y_sign * (top + bottom) / height,
+                sign * a,
+                sign,
+            ),
+            vector!(T::zero(), T::zero(), b, T::zero()),
+        )))
+    }
+
+    fn ortho_off_center(
+        left: T,
+        right: T,
+        bottom: T,
+        top: T,
+        near: T,
+        far: T,
+        config: &ProjectionConfig,
+    ) -> Result<Self, MatrixError> {
+        // An orthographic depth mapping has no finite limit as the far plane goes to infinity
+        if near == far || config.infinite_far {
+            return Err(MatrixError::IncorrectNearFarPlanes);
+        }
+        if left == right || bottom == top {
+            return Err(MatrixError::IncorrectFrustumBounds);
+        }
+
+        let sign = match config.handedness {
+            Handedness::LeftHanded => T::one(),
+            Handedness::RightHanded => -T::one(),
+        };
+        let y_sign = if config.flip_y { -T::one() } else { T::one() };
+        let (near_depth, far_depth) = depth_targets::<T>(config);
+        let a = (far_depth - near_depth) / (far - near);
+
+        let width = right - left;
+        let height = top - bottom;
+        Ok(Self::new(vector!(
+            vector!(T::two() / width, T::zero(), T::zero(), T::zero()),
+            vector!(T::zero(), y_sign * T::two() / height, T::zero(), T::zero()),
+            vector!(T::zero(), T::zero(), sign * a, T::zero()),
+            vector!(
+                -(right + left) / width,
+                -y_sign * (top + bottom) / height,
+                near_depth - a * near,
+                T::one(),
             ),
         )))
     }
 }
 
-impl From<Quaternion> for Matrix<f32, 4, 4> {
-    fn from(q: Quaternion) -> Self {
-        Self::new_rotation(&q).unwrap()
+// The normalized depth of the near and far planes
+fn depth_targets<T: Real>(config: &ProjectionConfig) -> (T, T) {
+    let (low, high) = match config.depth_range {
+        DepthRange::ZeroToOne => (T::zero(), T::one()),
+        DepthRange::NegativeOneToOne => (-T::one(), T::one()),
+    };
+    if config.reversed_z {
+        (high, low)
+    } else {
+        (low, high)
     }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MatrixError {
     NotSquare,
     TooFewRows,
     TooFewColumns,
     OutOfRangeFOV,
     IncorrectNearFarPlanes,
+    IncorrectFrustumBounds,
+    Singular,
+    NegativeScale,
+    IncorrectElementCount,
+    NotSymmetric,
+    NotPositiveDefinite,
+    NoConvergence,
+    IllConditioned,
+    DimensionMismatch,
     VectorError(VectorError),
+    QuaternionError(QuaternionError),
 }
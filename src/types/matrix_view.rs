@@ -0,0 +1,348 @@
+use crate::*;
+use std::fmt::{Debug, Formatter};
+use std::ops::{Index, IndexMut};
+
+// Borrowed rectangular block of a matrix. The elements start at the top-left cell of the block
+// and consecutive columns are stride elements apart, so every column is a contiguous slice
+pub struct MatrixView<'a, T, const COLUMNS: usize, const ROWS: usize> {
+    elements: &'a [T],
+    stride: usize,
+}
+
+pub struct MatrixViewMut<'a, T, const COLUMNS: usize, const ROWS: usize> {
+    elements: &'a mut [T],
+    stride: usize,
+}
+
+// Borrowed main diagonal of a square matrix
+pub struct DiagonalView<'a, T, const N: usize> {
+    elements: &'a [T],
+}
+
+pub struct DiagonalViewMut<'a, T, const N: usize> {
+    elements: &'a mut [T],
+}
+
+impl<'a, T, const COLUMNS: usize, const ROWS: usize> MatrixView<'a, T, COLUMNS, ROWS> {
+    pub fn column(&self, idx: usize) -> &'a [T] {
+        assert!(idx < COLUMNS, "column index out of range");
+        let elements: &'a [T] = self.elements;
+        &elements[idx * self.stride..idx * self.stride + ROWS]
+    }
+
+    pub fn row(&self, idx: usize) -> Vector<T, COLUMNS>
+    where
+        T: Clone,
+    {
+        Vector::new(init_array!([T; COLUMNS], |column_idx: usize| self
+            .column(column_idx)[idx]
+            .clone()))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = &'a [T]> + '_ {
+        (0..COLUMNS).map(move |idx| self.column(idx))
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = Vector<T, COLUMNS>> + '_
+    where
+        T: Clone,
+    {
+        (0..ROWS).map(move |idx| self.row(idx))
+    }
+
+    pub fn to_matrix(&self) -> Matrix<T, COLUMNS, ROWS>
+    where
+        T: Clone,
+    {
+        Matrix::from_array(init_array!([Vector<T, ROWS>; COLUMNS], |idx: usize| {
+            Vector::from_slice(self.column(idx)).unwrap()
+        }))
+    }
+}
+
+impl<'a, T, const COLUMNS: usize, const ROWS: usize> MatrixViewMut<'a, T, COLUMNS, ROWS> {
+    pub fn as_view(&self) -> MatrixView<'_, T, COLUMNS, ROWS> {
+        MatrixView {
+            elements: &*self.elements,
+            stride: self.stride,
+        }
+    }
+
+    pub fn column(&self, idx: usize) -> &[T] {
+        self.as_view().column(idx)
+    }
+
+    pub fn column_mut(&mut self, idx: usize) -> &mut [T] {
+        assert!(idx < COLUMNS, "column index out of range");
+        &mut self.elements[idx * self.stride..idx * self.stride + ROWS]
+    }
+
+    pub fn row(&self, idx: usize) -> Vector<T, COLUMNS>
+    where
+        T: Clone,
+    {
+        self.as_view().row(idx)
+    }
+
+    pub fn set_row(&mut self, idx: usize, row: Vector<T, COLUMNS>) {
+        for (column_idx, value) in row.into_iter().enumerate() {
+            self.column_mut(column_idx)[idx] = value;
+        }
+    }
+
+    pub fn to_matrix(&self) -> Matrix<T, COLUMNS, ROWS>
+    where
+        T: Clone,
+    {
+        self.as_view().to_matrix()
+    }
+
+    pub fn copy_from(&mut self, matrix: &Matrix<T, COLUMNS, ROWS>)
+    where
+        T: Clone,
+    {
+        for column_idx in 0..COLUMNS {
+            self.column_mut(column_idx)
+                .clone_from_slice(matrix[column_idx].as_slice());
+        }
+    }
+
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        for column_idx in 0..COLUMNS {
+            for element in self.column_mut(column_idx) {
+                *element = value.clone();
+            }
+        }
+    }
+}
+
+impl<'a, T, const N: usize> DiagonalView<'a, T, N> {
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> + '_ {
+        let elements: &'a [T] = self.elements;
+        elements.iter().step_by(N + 1)
+    }
+
+    pub fn to_vector(&self) -> Vector<T, N>
+    where
+        T: Clone,
+    {
+        Vector::new(init_array!([T; N], |idx: usize| self[idx].clone()))
+    }
+}
+
+impl<'a, T, const N: usize> DiagonalViewMut<'a, T, N> {
+    pub fn as_view(&self) -> DiagonalView<'_, T, N> {
+        DiagonalView {
+            elements: &*self.elements,
+        }
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> + '_ {
+        self.elements.iter_mut().step_by(N + 1)
+    }
+
+    pub fn to_vector(&self) -> Vector<T, N>
+    where
+        T: Clone,
+    {
+        self.as_view().to_vector()
+    }
+
+    pub fn copy_from(&mut self, diagonal: &Vector<T, N>)
+    where
+        T: Clone,
+    {
+        for (element, value) in self.iter_mut().zip(diagonal.iter()) {
+            *element = value.clone();
+        }
+    }
+}
+
+impl<T: Sized, const COLUMNS: usize, const ROWS: usize> Matrix<T, COLUMNS, ROWS> {
+    // Returns the slice offset of the block's first cell. An empty block may start past the last
+    // column or row, so the offset is clamped to keep the slice in range
+    fn check_block<const C: usize, const R: usize>(
+        column: usize,
+        row: usize,
+    ) -> Result<usize, MatrixError> {
+        if column.checked_add(C).map_or(true, |end| end > COLUMNS) {
+            return Err(MatrixError::TooFewColumns);
+        }
+        if row.checked_add(R).map_or(true, |end| end > ROWS) {
+            return Err(MatrixError::TooFewRows);
+        }
+        Ok((column * ROWS + row).min(COLUMNS * ROWS))
+    }
+
+    // View of the C x R block whose top-left cell is at (column, row)
+    pub fn fixed_view<const C: usize, const R: usize>(
+        &self,
+        column: usize,
+        row: usize,
+    ) -> Result<MatrixView<'_, T, C, R>, MatrixError> {
+        let start = Self::check_block::<C, R>(column, row)?;
+        Ok(MatrixView {
+            elements: &self.as_slice()[start..],
+            stride: ROWS,
+        })
+    }
+
+    pub fn fixed_view_mut<const C: usize, const R: usize>(
+        &mut self,
+        column: usize,
+        row: usize,
+    ) -> Result<MatrixViewMut<'_, T, C, R>, MatrixError> {
+        let start = Self::check_block::<C, R>(column, row)?;
+        Ok(MatrixViewMut {
+            elements: &mut self.as_mut_slice()[start..],
+            stride: ROWS,
+        })
+    }
+
+    pub fn as_view(&self) -> MatrixView<'_, T, COLUMNS, ROWS> {
+        self.fixed_view(0, 0).unwrap()
+    }
+
+    pub fn as_view_mut(&mut self) -> MatrixViewMut<'_, T, COLUMNS, ROWS> {
+        self.fixed_view_mut(0, 0).unwrap()
+    }
+
+    // Overwrites the block whose top-left cell is at (column, row)
+    pub fn copy_block_from<const C: usize, const R: usize>(
+        &mut self,
+        column: usize,
+        row: usize,
+        block: &Matrix<T, C, R>,
+    ) -> Result<(), MatrixError>
+    where
+        T: Clone,
+    {
+        self.fixed_view_mut(column, row)?.copy_from(block);
+        Ok(())
+    }
+}
+
+impl<T: Sized, const N: usize> Matrix<T, N, N> {
+    pub fn diagonal(&self) -> DiagonalView<'_, T, N> {
+        DiagonalView {
+            elements: self.as_slice(),
+        }
+    }
+
+    pub fn diagonal_mut(&mut self) -> DiagonalViewMut<'_, T, N> {
+        DiagonalViewMut {
+            elements: self.as_mut_slice(),
+        }
+    }
+}
+
+// The linear (rotation and scale) part of a transform
+impl<T: Sized> Matrix<T, 4, 4> {
+    pub fn upper_left_3x3(&self) -> MatrixView<'_, T, 3, 3> {
+        self.fixed_view(0, 0).unwrap()
+    }
+
+    pub fn upper_left_3x3_mut(&mut self) -> MatrixViewMut<'_, T, 3, 3> {
+        self.fixed_view_mut(0, 0).unwrap()
+    }
+}
+
+impl<'a, T, const COLUMNS: usize, const ROWS: usize> Clone for MatrixView<'a, T, COLUMNS, ROWS> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T, const COLUMNS: usize, const ROWS: usize> Copy for MatrixView<'a, T, COLUMNS, ROWS> {}
+
+impl<'a, T, const N: usize> Clone for DiagonalView<'a, T, N> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T, const N: usize> Copy for DiagonalView<'a, T, N> {}
+
+impl<'a, T, const COLUMNS: usize, const ROWS: usize> Index<usize>
+    for MatrixView<'a, T, COLUMNS, ROWS>
+{
+    type Output = [T];
+
+    fn index(&self, idx: usize) -> &[T] {
+        self.column(idx)
+    }
+}
+
+impl<'a, T, const COLUMNS: usize, const ROWS: usize> Index<usize>
+    for MatrixViewMut<'a, T, COLUMNS, ROWS>
+{
+    type Output = [T];
+
+    fn index(&self, idx: usize) -> &[T] {
+        self.column(idx)
+    }
+}
+
+impl<'a, T, const COLUMNS: usize, const ROWS: usize> IndexMut<usize>
+    for MatrixViewMut<'a, T, COLUMNS, ROWS>
+{
+    fn index_mut(&mut self, idx: usize) -> &mut [T] {
+        self.column_mut(idx)
+    }
+}
+
+impl<'a, T, const N: usize> Index<usize> for DiagonalView<'a, T, N> {
+    type Output = T;
+
+    fn index(&self, idx: usize) -> &T {
+        assert!(idx < N, "diagonal index out of range");
+        &self.elements[idx * (N + 1)]
+    }
+}
+
+impl<'a, T, const N: usize> Index<usize> for DiagonalViewMut<'a, T, N> {
+    type Output = T;
+
+    fn index(&self, idx: usize) -> &T {
+        assert!(idx < N, "diagonal index out of range");
+        &self.elements[idx * (N + 1)]
+    }
+}
+
+impl<'a, T, const N: usize> IndexMut<usize> for DiagonalViewMut<'a, T, N> {
+    fn index_mut(&mut self, idx: usize) -> &mut T {
+        assert!(idx < N, "diagonal index out of range");
+        &mut self.elements[idx * (N + 1)]
+    }
+}
+
+impl<'a, T: Debug, const COLUMNS: usize, const ROWS: usize> Debug
+    for MatrixView<'a, T, COLUMNS, ROWS>
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.columns()).finish()
+    }
+}
+
+impl<'a, T: Debug, const COLUMNS: usize, const ROWS: usize> Debug
+    for MatrixViewMut<'a, T, COLUMNS, ROWS>
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.as_view().fmt(f)
+    }
+}
+
+impl<'a, T: Debug, const N: usize> Debug for DiagonalView<'a, T, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, T: Debug, const N: usize> Debug for DiagonalViewMut<'a, T, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.as_view().fmt(f)
+    }
+}
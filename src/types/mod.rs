@@ -1,12 +1,29 @@
 mod vector;
 pub use vector::*;
 
+mod swizzle;
+
 mod matrix;
 pub use matrix::*;
 
+mod matrix_view;
+pub use matrix_view::*;
+
+mod decomposition;
+pub use decomposition::*;
+
+mod dvector;
+pub use dvector::*;
+
+mod dmatrix;
+pub use dmatrix::*;
+
 mod quaternion;
 pub use quaternion::*;
 
+mod transform;
+pub use transform::*;
+
 mod gaussian;
 pub use gaussian::*;
 
@@ -15,3 +32,9 @@ pub use rand::*;
 
 mod val_rand;
 pub use val_rand::*;
+
+mod nnet;
+pub use nnet::*;
+
+#[cfg(feature = "serde")]
+mod serde_impls;
@@ -0,0 +1,315 @@
+use crate::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Activation {
+    Sigmoid,
+    Tanh,
+    Relu,
+}
+
+impl Activation {
+    pub fn apply(self, x: f64) -> f64 {
+        match self {
+            Activation::Sigmoid => 1.0 / (1.0 + (-x).exp()),
+            Activation::Tanh => x.tanh(),
+            Activation::Relu => x.max(0.0),
+        }
+    }
+
+    // Derivative in terms of the activation's output rather than its input
+    pub fn derivative(self, y: f64) -> f64 {
+        match self {
+            Activation::Sigmoid => y * (1.0 - y),
+            Activation::Tanh => 1.0 - y * y,
+            Activation::Relu => {
+                if y > 0.0 {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+        }
+    }
+}
+
+// Activations of every layer from the last forward pass, needed by the backward pass
+#[derive(Clone, Debug)]
+struct ForwardPass<const INPUTS: usize, const OUTPUTS: usize, const HIDDEN_SIZE: usize> {
+    input: Vector<f64, INPUTS>,
+    hidden: Vec<Vector<f64, HIDDEN_SIZE>>,
+    output: Vector<f64, OUTPUTS>,
+}
+
+// A fully connected feed-forward network trained by backpropagation on the squared error
+#[derive(Clone, Debug)]
+pub struct NNet<
+    const INPUTS: usize,
+    const OUTPUTS: usize,
+    const HIDDEN_LAYERS: usize,
+    const HIDDEN_SIZE: usize,
+> {
+    input_weights: Matrix<f64, INPUTS, HIDDEN_SIZE>,
+    input_biases: Vector<f64, HIDDEN_SIZE>,
+    hidden_weights: Vec<Matrix<f64, HIDDEN_SIZE, HIDDEN_SIZE>>,
+    hidden_biases: Vec<Vector<f64, HIDDEN_SIZE>>,
+    output_weights: Matrix<f64, HIDDEN_SIZE, OUTPUTS>,
+    output_biases: Vector<f64, OUTPUTS>,
+    learning_rate: f64,
+    hidden_activation: Activation,
+    output_activation: Activation,
+    last_pass: Option<ForwardPass<INPUTS, OUTPUTS, HIDDEN_SIZE>>,
+}
+
+impl<
+        const INPUTS: usize,
+        const OUTPUTS: usize,
+        const HIDDEN_LAYERS: usize,
+        const HIDDEN_SIZE: usize,
+    > NNet<INPUTS, OUTPUTS, HIDDEN_LAYERS, HIDDEN_SIZE>
+{
+    // Weights are drawn from a Gaussian2 seeded from the given seed and scaled by the number of
+    // inputs to each layer; biases start at zero
+    pub fn new(seed: &mut u64, learning_rate: f64) -> Result<Self, NNetError> {
+        if HIDDEN_LAYERS == 0 {
+            return Err(NNetError::NoHiddenLayers);
+        }
+        if HIDDEN_SIZE == 0 {
+            return Err(NNetError::ZeroHiddenSize);
+        }
+
+        let mut gaussian = Gaussian2::<f64>::new(u64::rand_next(seed));
+        Ok(Self {
+            input_weights: random_weights(&mut gaussian),
+            input_biases: Vector::zero(),
+            hidden_weights: (1..HIDDEN_LAYERS)
+                .map(|_| random_weights(&mut gaussian))
+                .collect(),
+            hidden_biases: vec![Vector::zero(); HIDDEN_LAYERS - 1],
+            output_weights: random_weights(&mut gaussian),
+            output_biases: Vector::zero(),
+            learning_rate,
+            hidden_activation: Activation::Sigmoid,
+            output_activation: Activation::Sigmoid,
+            last_pass: None,
+        })
+    }
+
+    pub fn from_weights(weights: &[f64], learning_rate: f64) -> Result<Self, NNetError> {
+        let mut nnet = Self::new(&mut 0, learning_rate)?;
+        nnet.set_weights(weights)?;
+        Ok(nnet)
+    }
+
+    pub fn learning_rate(&self) -> f64 {
+        self.learning_rate
+    }
+
+    pub fn set_learning_rate(&mut self, learning_rate: f64) {
+        self.learning_rate = learning_rate;
+    }
+
+    pub fn hidden_activation(&self) -> Activation {
+        self.hidden_activation
+    }
+
+    pub fn set_hidden_activation(&mut self, activation: Activation) {
+        self.hidden_activation = activation;
+    }
+
+    pub fn output_activation(&self) -> Activation {
+        self.output_activation
+    }
+
+    pub fn set_output_activation(&mut self, activation: Activation) {
+        self.output_activation = activation;
+    }
+
+    pub fn forward(&mut self, inputs: &[f64]) -> Result<Vector<f64, OUTPUTS>, NNetError> {
+        let input = Vector::from_slice(inputs).map_err(NNetError::VectorError)?;
+
+        let mut hidden = Vec::with_capacity(HIDDEN_LAYERS);
+        hidden.push(layer_forward(
+            &self.input_weights,
+            &self.input_biases,
+            &input,
+            self.hidden_activation,
+        ));
+        for (weights, biases) in self.hidden_weights.iter().zip(self.hidden_biases.iter()) {
+            let next = layer_forward(
+                weights,
+                biases,
+                &hidden[hidden.len() - 1],
+                self.hidden_activation,
+            );
+            hidden.push(next);
+        }
+        let output = layer_forward(
+            &self.output_weights,
+            &self.output_biases,
+            &hidden[HIDDEN_LAYERS - 1],
+            self.output_activation,
+        );
+
+        self.last_pass = Some(ForwardPass {
+            input,
+            hidden,
+            output,
+        });
+        Ok(output)
+    }
+
+    // Adjusts the weights towards producing the targets for the inputs of the last forward pass,
+    // returning the error of that pass
+    pub fn backward(&mut self, targets: &[f64]) -> Result<f64, NNetError> {
+        let targets: Vector<f64, OUTPUTS> =
+            Vector::from_slice(targets).map_err(NNetError::VectorError)?;
+        let pass = self.last_pass.take().ok_or(NNetError::NoForwardPass)?;
+        let (learning_rate, hidden_activation, output_activation) = (
+            self.learning_rate,
+            self.hidden_activation,
+            self.output_activation,
+        );
+
+        let error = pass
+            .output
+            .zip_with(targets, |output, target| output - target);
+        let loss = error.fold(0.0, |loss, error| loss + error * error) / 2.0;
+
+        let delta = error.zip_with(pass.output, |error, output| {
+            error * output_activation.derivative(output)
+        });
+        let mut delta = layer_backward(
+            &mut self.output_weights,
+            &mut self.output_biases,
+            &pass.hidden[HIDDEN_LAYERS - 1],
+            &delta,
+            learning_rate,
+        )
+        .zip_with(pass.hidden[HIDDEN_LAYERS - 1], |error, output| {
+            error * hidden_activation.derivative(output)
+        });
+        for layer in (0..HIDDEN_LAYERS - 1).rev() {
+            delta = layer_backward(
+                &mut self.hidden_weights[layer],
+                &mut self.hidden_biases[layer],
+                &pass.hidden[layer],
+                &delta,
+                learning_rate,
+            )
+            .zip_with(pass.hidden[layer], |error, output| {
+                error * hidden_activation.derivative(output)
+            });
+        }
+        layer_backward(
+            &mut self.input_weights,
+            &mut self.input_biases,
+            &pass.input,
+            &delta,
+            learning_rate,
+        );
+
+        Ok(loss)
+    }
+
+    pub fn weight_count(&self) -> usize {
+        (INPUTS + 1) * HIDDEN_SIZE
+            + (HIDDEN_LAYERS - 1) * (HIDDEN_SIZE + 1) * HIDDEN_SIZE
+            + (HIDDEN_SIZE + 1) * OUTPUTS
+    }
+
+    // All weights and biases layer by layer, each layer's weights in column-major order followed
+    // by its biases
+    pub fn weights(&self) -> Vec<f64> {
+        let mut weights = Vec::with_capacity(self.weight_count());
+        weights.extend_from_slice(self.input_weights.as_slice());
+        weights.extend_from_slice(self.input_biases.as_slice());
+        for (layer_weights, biases) in self.hidden_weights.iter().zip(self.hidden_biases.iter()) {
+            weights.extend_from_slice(layer_weights.as_slice());
+            weights.extend_from_slice(biases.as_slice());
+        }
+        weights.extend_from_slice(self.output_weights.as_slice());
+        weights.extend_from_slice(self.output_biases.as_slice());
+        weights
+    }
+
+    pub fn set_weights(&mut self, weights: &[f64]) -> Result<(), NNetError> {
+        if weights.len() != self.weight_count() {
+            return Err(NNetError::IncorrectWeightCount);
+        }
+
+        let mut remaining = weights;
+        let mut take = |slice: &mut [f64]| {
+            let (head, tail) = remaining.split_at(slice.len());
+            slice.copy_from_slice(head);
+            remaining = tail;
+        };
+        take(self.input_weights.as_mut_slice());
+        take(self.input_biases.as_mut_slice());
+        for (layer_weights, biases) in self
+            .hidden_weights
+            .iter_mut()
+            .zip(self.hidden_biases.iter_mut())
+        {
+            take(layer_weights.as_mut_slice());
+            take(biases.as_mut_slice());
+        }
+        take(self.output_weights.as_mut_slice());
+        take(self.output_biases.as_mut_slice());
+        self.last_pass = None;
+        Ok(())
+    }
+}
+
+fn random_weights<const INPUTS: usize, const OUTPUTS: usize>(
+    gaussian: &mut Gaussian2<f64>,
+) -> Matrix<f64, INPUTS, OUTPUTS> {
+    let scale = 1.0 / (INPUTS as f64).sqrt();
+    let weights: Vec<f64> = (0..INPUTS * OUTPUTS)
+        .map(|_| (gaussian.next() * 2.0 - 1.0) * scale)
+        .collect();
+    Matrix::from_slice(&weights).unwrap()
+}
+
+fn layer_forward<const INPUTS: usize, const OUTPUTS: usize>(
+    weights: &Matrix<f64, INPUTS, OUTPUTS>,
+    biases: &Vector<f64, OUTPUTS>,
+    input: &Vector<f64, INPUTS>,
+    activation: Activation,
+) -> Vector<f64, OUTPUTS> {
+    weights
+        .mul_vector(input)
+        .zip_with(*biases, |sum, bias| activation.apply(sum + bias))
+}
+
+// Applies the gradient descent step for a layer and returns the error propagated to its inputs
+fn layer_backward<const INPUTS: usize, const OUTPUTS: usize>(
+    weights: &mut Matrix<f64, INPUTS, OUTPUTS>,
+    biases: &mut Vector<f64, OUTPUTS>,
+    input: &Vector<f64, INPUTS>,
+    delta: &Vector<f64, OUTPUTS>,
+    learning_rate: f64,
+) -> Vector<f64, INPUTS> {
+    let propagated = Vector::new(init_array!([f64; INPUTS], |idx: usize| weights
+        .column(idx)
+        .dot(delta)));
+    for (idx, input) in input.iter().enumerate() {
+        for (weight, delta) in weights.column_mut(idx).iter_mut().zip(delta.iter()) {
+            *weight -= learning_rate * delta * input;
+        }
+    }
+    for (bias, delta) in biases.iter_mut().zip(delta.iter()) {
+        *bias -= learning_rate * delta;
+    }
+    propagated
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NNetError {
+    NoHiddenLayers,
+    ZeroHiddenSize,
+    NoForwardPass,
+    IncorrectWeightCount,
+    VectorError(VectorError),
+}
@@ -1,137 +1,405 @@
 use crate::*;
+use std::iter::Sum;
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
 
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 #[repr(C)]
-pub struct Quaternion {
-    components: Vector<f32, 4>,
+pub struct Quaternion<T = f32> {
+    components: Vector<T, 4>,
 }
 
-impl Quaternion {
+unsafe impl<T: Zeroable> Zeroable for Quaternion<T> {}
+unsafe impl<T: Pod> Pod for Quaternion<T> {}
+
+crate::assert_layout!(Quaternion<f32>, size: 16, align: 4);
+crate::assert_layout!(Quaternion<f64>, size: 32, align: 8);
+
+impl Quaternion<f32> {
     pub const IDENTITY: Self = Self::new(0.0, 0.0, 0.0, 1.0);
+}
 
-    pub const fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
+impl<T> Quaternion<T> {
+    pub const fn new(x: T, y: T, z: T, w: T) -> Self {
         Self {
             components: vector!(x, y, z, w),
         }
     }
 
-    pub fn from_vec(xyz: Vector<f32, 3>, w: f32) -> Self {
-        Self {
-            components: vector!(xyz[0], xyz[1], xyz[2], w),
-        }
-    }
-
-    pub fn from_vec4(xyzw: Vector<f32, 4>) -> Self {
+    pub fn from_vec4(xyzw: Vector<T, 4>) -> Self {
         Self { components: xyzw }
     }
+}
 
-    pub fn from_axis_angle(axis: Vector<f32, 3>, radians: f32) -> Result<Self, &'static str> {
-        let normal = axis
-            .normalized()
-            .map_err(|_| "Axis has a length of 0 or close to 0")?;
-        let half_angle = radians * 0.5;
-        Ok(
-            Quaternion::from_vec(normal * half_angle.sin(), half_angle.cos())
-                .normalized()
-                .unwrap(),
-        )
+impl<T: Copy> Quaternion<T> {
+    pub fn from_vec(xyz: Vector<T, 3>, w: T) -> Self {
+        Self {
+            components: vector!(xyz[0], xyz[1], xyz[2], w),
+        }
     }
 
-    pub fn x(&self) -> f32 {
+    pub fn x(&self) -> T {
         *self.components.x()
     }
 
-    pub fn y(&self) -> f32 {
+    pub fn y(&self) -> T {
         *self.components.y()
     }
 
-    pub fn z(&self) -> f32 {
+    pub fn z(&self) -> T {
         *self.components.z()
     }
 
-    pub fn w(&self) -> f32 {
+    pub fn w(&self) -> T {
         *self.components.w()
     }
 
-    pub fn xyz(&self) -> Vector<f32, 3> {
+    pub fn xyz(&self) -> Vector<T, 3> {
         vector!(self.components[0], self.components[1], self.components[2])
     }
 
-    pub fn xyzw(&self) -> Vector<f32, 4> {
+    pub fn xyzw(&self) -> Vector<T, 4> {
         self.components
     }
 
-    pub fn set_x(&mut self, val: f32) {
+    pub fn set_x(&mut self, val: T) {
         self.components[0] = val;
     }
 
-    pub fn set_y(&mut self, val: f32) {
+    pub fn set_y(&mut self, val: T) {
         self.components[1] = val;
     }
 
-    pub fn set_z(&mut self, val: f32) {
+    pub fn set_z(&mut self, val: T) {
         self.components[2] = val;
     }
 
-    pub fn set_w(&mut self, val: f32) {
+    pub fn set_w(&mut self, val: T) {
         self.components[3] = val;
     }
 
-    pub fn set_xyz(&mut self, val: Vector<f32, 3>) {
+    pub fn set_xyz(&mut self, val: Vector<T, 3>) {
         self.components[0] = val[0];
         self.components[1] = val[1];
         self.components[2] = val[2];
     }
 
-    pub fn length(&self) -> f32 {
+    pub fn convert<T2: Copy>(&self) -> Quaternion<T2>
+    where
+        T: Into<T2>,
+    {
+        Quaternion::from_vec4(self.components.convert())
+    }
+}
+
+impl<T> Quaternion<T>
+where
+    T: Real,
+{
+    pub fn identity() -> Self {
+        Self::new(T::zero(), T::zero(), T::zero(), T::one())
+    }
+
+    pub fn from_axis_angle(axis: Vector<T, 3>, radians: T) -> Result<Self, &'static str> {
+        let normal = axis
+            .normalized()
+            .map_err(|_| "Axis has a length of 0 or close to 0")?;
+        let half_angle = radians / T::two();
+        Ok(
+            Quaternion::from_vec(normal * half_angle.sin(), half_angle.cos())
+                .normalized()
+                .unwrap(),
+        )
+    }
+
+    pub fn length(&self) -> T {
         self.length_squared().sqrt()
     }
 
-    pub fn length_squared(&self) -> f32 {
+    pub fn length_squared(&self) -> T {
         self.w() * self.w() + self.xyz().length2().unwrap()
     }
 
-    pub fn normalized(&self) -> Result<Quaternion, QuaternionError> {
-        let len_sq = self.length_squared();
-        if len_sq < 0.00001 {
+    // Whether the length is too small, relative to the components, to divide by
+    fn is_zero_length(&self) -> bool {
+        let largest = self
+            .components
+            .iter()
+            .fold(T::zero(), |largest, component| {
+                largest.max(&component.abs())
+            });
+        self.length() <= zero_tolerance(largest)
+    }
+
+    pub fn normalized(&self) -> Result<Self, QuaternionError> {
+        if self.is_zero_length() {
             Err(QuaternionError::ZeroLength)
         } else {
-            let len = len_sq.sqrt();
+            let len = self.length();
             Ok(Quaternion::from_vec(self.xyz() / len, self.w() / len))
         }
     }
 
-    pub fn invert(&mut self) -> Quaternion {
-        let len_sq = self.length_squared();
-        if len_sq.abs() > 0.00001 {
-            let i = 1.0 / len_sq;
+    pub fn invert(&mut self) -> Self {
+        if !self.is_zero_length() {
+            let i = T::one() / self.length_squared();
             Quaternion::from_vec(self.xyz() * -i, self.w() * i)
         } else {
             *self
         }
     }
 
-    pub fn axis_angle(&self) -> (Vector<f32, 3>, f32) {
-        let norm = if self.w() > 1.0 {
+    pub fn axis_angle(&self) -> (Vector<T, 3>, T) {
+        let norm = if self.w() > T::one() {
             self.normalized().unwrap()
         } else {
             *self
         };
-        let w = 2.0 * norm.w().acos();
-        let den = (1.0 - norm.w() * norm.w()).sqrt();
-        if den > 0.00001 {
+        let w = T::two() * norm.w().acos();
+        // norm is a unit quaternion, so the axis is measured against a length of one
+        let den = (T::one() - norm.w() * norm.w()).sqrt();
+        if den > zero_tolerance(T::one()) {
             (norm.xyz() / den, w)
         } else {
-            (vector!(1.0, 0.0, 0.0), 0.0)
+            (vector!(T::one(), T::zero(), T::zero()), T::zero())
         }
     }
 
     pub fn conjugate(&self) -> Self {
-        Quaternion::from_vec(vector!(0.0, 0.0, 0.0) - self.xyz(), self.w())
+        Quaternion::from_vec(-self.xyz(), self.w())
+    }
+
+    pub fn dot(&self, other: &Self) -> T {
+        self.components.dot(&other.components)
+    }
+
+    pub fn angle_between(&self, other: &Self) -> T {
+        let other = if self.dot(other) < T::zero() {
+            -*other
+        } else {
+            *other
+        };
+        T::from(4.0) * (*self - other).length().atan2(&(*self + other).length())
+    }
+
+    // Like abs_diff_eq, but q and -q are considered equal since they describe the same rotation
+    pub fn rotation_abs_diff_eq(&self, other: &Self, epsilon: T) -> bool
+    where
+        T: ApproxEq<Epsilon = T>,
+    {
+        self.abs_diff_eq(other, epsilon) || self.abs_diff_eq(&-*other, epsilon)
+    }
+
+    pub fn rotate_vector(&self, vector: Vector<T, 3>) -> Vector<T, 3> {
+        let xyz = self.xyz();
+        let t = xyz.cross(&vector) * T::two();
+        vector + t * self.w() + xyz.cross(&t)
+    }
+
+    pub fn nlerp(&self, other: &Self, t: T) -> Self {
+        let other = if self.dot(other) < T::zero() {
+            -*other
+        } else {
+            *other
+        };
+        let lerped = *self * (T::one() - t) + other * t;
+        lerped / lerped.length()
+    }
+
+    pub fn slerp(&self, other: &Self, t: T) -> Self {
+        let mut dot = self.dot(other);
+        let other = if dot < T::zero() {
+            dot = -dot;
+            -*other
+        } else {
+            *other
+        };
+        if dot > T::from(0.9995) {
+            return self.nlerp(&other, t);
+        }
+        let theta = dot.acos();
+        let sin_theta = theta.sin();
+        let a = ((T::one() - t) * theta).sin() / sin_theta;
+        let b = (t * theta).sin() / sin_theta;
+        *self * a + other * b
+    }
+
+    pub fn from_euler(angles: Vector<T, 3>, order: EulerOrder) -> Self {
+        let [i, j, k] = order.axes();
+        let rotation = |axis: usize| {
+            let mut axis_vector = Vector::<T, 3>::zero();
+            axis_vector[axis] = T::one();
+            Quaternion::from_axis_angle(axis_vector, angles[axis]).unwrap()
+        };
+        rotation(k) * rotation(j) * rotation(i)
+    }
+
+    pub fn to_euler(&self, order: EulerOrder) -> Vector<T, 3> {
+        let m = self.rotation_rows();
+        let [i, j, k] = order.axes();
+        let sign = if order.is_even() { T::one() } else { -T::one() };
+        let mut angles = Vector::<T, 3>::zero();
+        angles[i] = (sign * m[k][j]).atan2(&m[k][k]);
+        angles[j] = (-sign * m[k][i]).atan2(&(m[k][j] * m[k][j] + m[k][k] * m[k][k]).sqrt());
+
+        // Undo the first rotation and read the last one from what is left, which stays accurate
+        // near gimbal lock where the first and last angles are no longer independent
+        let (sin_i, cos_i) = angles[i].sin_cos();
+        let column_j = |row: usize| cos_i * m[row][j] - sign * sin_i * m[row][k];
+        angles[k] = (-sign * column_j(i)).atan2(&column_j(j));
+        angles
+    }
+
+    pub fn from_rotation_arc(
+        from: Vector<T, 3>,
+        to: Vector<T, 3>,
+    ) -> Result<Self, QuaternionError> {
+        let from_length: T = from.length().map_err(QuaternionError::VectorError)?;
+        let to_length: T = to.length().map_err(QuaternionError::VectorError)?;
+        let tolerance = zero_tolerance(from_length.max(&to_length));
+        if from_length <= tolerance || to_length <= tolerance {
+            return Err(QuaternionError::ZeroLength);
+        }
+        let from = from / from_length;
+        let to = to / to_length;
+        let dot = from.dot(&to);
+        if dot < zero_tolerance(T::one()) - T::one() {
+            // Opposite directions, so rotate half a turn around any perpendicular axis
+            let (axis, _) = from.any_orthonormal_basis();
+            return Ok(Quaternion::from_vec(axis, T::zero()));
+        }
+        Quaternion::from_vec(from.cross(&to), T::one() + dot).normalized()
+    }
+
+    pub fn from_rotation_matrix(matrix: &Matrix<T, 3, 3>) -> Self {
+        Self::from_rotation_rows([
+            [matrix[0][0], matrix[1][0], matrix[2][0]],
+            [matrix[0][1], matrix[1][1], matrix[2][1]],
+            [matrix[0][2], matrix[1][2], matrix[2][2]],
+        ])
+    }
+
+    // Local -Z faces forward, as in TransformMatrix::view. Errs if up is zero or along forward
+    pub fn look_rotation(forward: Vector<T, 3>, up: Vector<T, 3>) -> Result<Self, QuaternionError> {
+        let forward_length: T = forward.length().map_err(QuaternionError::VectorError)?;
+        let up_length: T = up.length().map_err(QuaternionError::VectorError)?;
+        if forward_length <= zero_tolerance(forward_length.max(&up_length)) {
+            return Err(QuaternionError::ZeroLength);
+        }
+        let zaxis = -(forward / forward_length);
+        let xaxis = up.cross(&zaxis);
+        let xaxis_length: T = xaxis.length().map_err(QuaternionError::VectorError)?;
+        if xaxis_length <= zero_tolerance(up_length) {
+            return Err(QuaternionError::ZeroLength);
+        }
+        let xaxis = xaxis / xaxis_length;
+        let yaxis = zaxis.cross(&xaxis);
+        Ok(Self::from_rotation_rows([
+            [xaxis[0], yaxis[0], zaxis[0]],
+            [xaxis[1], yaxis[1], zaxis[1]],
+            [xaxis[2], yaxis[2], zaxis[2]],
+        ]))
+    }
+
+    fn rotation_rows(&self) -> [[T; 3]; 3] {
+        let (x, y, z, w) = (self.x(), self.y(), self.z(), self.w());
+        let (one, two) = (T::one(), T::two());
+        [
+            [
+                one - two * (y * y + z * z),
+                two * (x * y - w * z),
+                two * (x * z + w * y),
+            ],
+            [
+                two * (x * y + w * z),
+                one - two * (x * x + z * z),
+                two * (y * z - w * x),
+            ],
+            [
+                two * (x * z - w * y),
+                two * (y * z + w * x),
+                one - two * (x * x + y * y),
+            ],
+        ]
+    }
+
+    fn from_rotation_rows(m: [[T; 3]; 3]) -> Self {
+        let (one, two, quarter) = (T::one(), T::two(), T::from(0.25));
+        // Shepperd's method, which solves for the largest component first to stay accurate
+        let trace = m[0][0] + m[1][1] + m[2][2];
+        if trace >= m[0][0] && trace >= m[1][1] && trace >= m[2][2] {
+            let s = (trace + one).sqrt() * two;
+            Self::new(
+                (m[2][1] - m[1][2]) / s,
+                (m[0][2] - m[2][0]) / s,
+                (m[1][0] - m[0][1]) / s,
+                quarter * s,
+            )
+        } else if m[0][0] >= m[1][1] && m[0][0] >= m[2][2] {
+            let s = (one + m[0][0] - m[1][1] - m[2][2]).sqrt() * two;
+            Self::new(
+                quarter * s,
+                (m[0][1] + m[1][0]) / s,
+                (m[0][2] + m[2][0]) / s,
+                (m[2][1] - m[1][2]) / s,
+            )
+        } else if m[1][1] >= m[2][2] {
+            let s = (one + m[1][1] - m[0][0] - m[2][2]).sqrt() * two;
+            Self::new(
+                (m[0][1] + m[1][0]) / s,
+                quarter * s,
+                (m[1][2] + m[2][1]) / s,
+                (m[0][2] - m[2][0]) / s,
+            )
+        } else {
+            let s = (one + m[2][2] - m[0][0] - m[1][1]).sqrt() * two;
+            Self::new(
+                (m[0][2] + m[2][0]) / s,
+                (m[1][2] + m[2][1]) / s,
+                quarter * s,
+                (m[1][0] - m[0][1]) / s,
+            )
+        }
+    }
+}
+
+// The order in which rotations around the fixed X, Y and Z axes are applied, so `XYZ` rotates
+// around X first and Z last
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EulerOrder {
+    XYZ,
+    XZY,
+    YXZ,
+    YZX,
+    ZXY,
+    ZYX,
+}
+
+impl EulerOrder {
+    pub fn axes(&self) -> [usize; 3] {
+        match self {
+            EulerOrder::XYZ => [0, 1, 2],
+            EulerOrder::XZY => [0, 2, 1],
+            EulerOrder::YXZ => [1, 0, 2],
+            EulerOrder::YZX => [1, 2, 0],
+            EulerOrder::ZXY => [2, 0, 1],
+            EulerOrder::ZYX => [2, 1, 0],
+        }
+    }
+
+    pub fn is_even(&self) -> bool {
+        matches!(self, EulerOrder::XYZ | EulerOrder::YZX | EulerOrder::ZXY)
     }
 }
 
-impl std::ops::Add<Self> for Quaternion {
+impl<T> Add<Self> for Quaternion<T>
+where
+    T: Add<T, Output = T> + Copy,
+{
     type Output = Self;
 
     fn add(self, other: Self) -> Self {
@@ -139,15 +407,21 @@ impl std::ops::Add<Self> for Quaternion {
     }
 }
 
-impl std::ops::Add<f32> for Quaternion {
+impl<T> Add<T> for Quaternion<T>
+where
+    T: Add<T, Output = T> + Copy,
+{
     type Output = Self;
 
-    fn add(self, other: f32) -> Self {
+    fn add(self, other: T) -> Self {
         Self::from_vec4(self.xyzw() + other)
     }
 }
 
-impl std::ops::Sub<Self> for Quaternion {
+impl<T> Sub<Self> for Quaternion<T>
+where
+    T: Sub<T, Output = T> + Copy,
+{
     type Output = Self;
 
     fn sub(self, other: Self) -> Self {
@@ -155,38 +429,50 @@ impl std::ops::Sub<Self> for Quaternion {
     }
 }
 
-impl std::ops::Sub<f32> for Quaternion {
+impl<T> Sub<T> for Quaternion<T>
+where
+    T: Sub<T, Output = T> + Copy,
+{
     type Output = Self;
 
-    fn sub(self, other: f32) -> Self {
+    fn sub(self, other: T) -> Self {
         Self::from_vec4(self.xyzw() - other)
     }
 }
 
-impl std::ops::Mul<Self> for Quaternion {
+impl<T> Mul<Self> for Quaternion<T>
+where
+    T: Add<T, Output = T> + Sub<T, Output = T> + Mul<T, Output = T> + Sum + Copy,
+{
     type Output = Self;
 
     fn mul(self, other: Self) -> Self {
         let cross = self.xyz().cross(&other.xyz());
         let dot = self.xyz().dot(&other.xyz());
         Self::new(
-            self.x() * other.w() + other.x() * self.w() + cross.x(),
-            self.y() * other.w() + other.y() * self.w() + cross.y(),
-            self.z() * other.w() + other.z() * self.w() + cross.z(),
+            self.x() * other.w() + other.x() * self.w() + *cross.x(),
+            self.y() * other.w() + other.y() * self.w() + *cross.y(),
+            self.z() * other.w() + other.z() * self.w() + *cross.z(),
             self.w() * other.w() - dot,
         )
     }
 }
 
-impl std::ops::Mul<f32> for Quaternion {
+impl<T> Mul<T> for Quaternion<T>
+where
+    T: Mul<T, Output = T> + Copy,
+{
     type Output = Self;
 
-    fn mul(self, other: f32) -> Self {
+    fn mul(self, other: T) -> Self {
         Self::from_vec4(self.xyzw() * other)
     }
 }
 
-impl std::ops::Div<Self> for Quaternion {
+impl<T> Div<Self> for Quaternion<T>
+where
+    T: Div<T, Output = T> + Copy,
+{
     type Output = Self;
 
     fn div(self, other: Self) -> Self {
@@ -194,15 +480,21 @@ impl std::ops::Div<Self> for Quaternion {
     }
 }
 
-impl std::ops::Div<f32> for Quaternion {
+impl<T> Div<T> for Quaternion<T>
+where
+    T: Div<T, Output = T> + Copy,
+{
     type Output = Self;
 
-    fn div(self, other: f32) -> Self {
+    fn div(self, other: T) -> Self {
         Self::from_vec4(self.xyzw() / other)
     }
 }
 
-impl std::ops::Rem<Self> for Quaternion {
+impl<T> Rem<Self> for Quaternion<T>
+where
+    T: Rem<T, Output = T> + Copy,
+{
     type Output = Self;
 
     fn rem(self, other: Self) -> Self {
@@ -210,23 +502,93 @@ impl std::ops::Rem<Self> for Quaternion {
     }
 }
 
-impl std::ops::Rem<f32> for Quaternion {
+impl<T> Rem<T> for Quaternion<T>
+where
+    T: Rem<T, Output = T> + Copy,
+{
     type Output = Self;
 
-    fn rem(self, other: f32) -> Self {
+    fn rem(self, other: T) -> Self {
         Self::from_vec4(self.xyzw() % other)
     }
 }
 
-impl std::ops::Neg for Quaternion {
+impl<T> Neg for Quaternion<T>
+where
+    T: Neg<Output = T> + Copy,
+{
     type Output = Self;
 
     fn neg(self) -> Self {
-        Self::from_vec4(vector!(0.0, 0.0, 0.0, 0.0) - self.xyzw())
+        Self::from_vec4(-self.xyzw())
+    }
+}
+
+impl<T> ApproxEq for Quaternion<T>
+where
+    T: ApproxEq,
+{
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> T::Epsilon {
+        T::default_epsilon()
+    }
+
+    fn default_max_relative() -> T::Epsilon {
+        T::default_max_relative()
+    }
+
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: T::Epsilon) -> bool {
+        self.components.abs_diff_eq(&other.components, epsilon)
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: T::Epsilon, max_relative: T::Epsilon) -> bool {
+        self.components
+            .relative_eq(&other.components, epsilon, max_relative)
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+        self.components
+            .ulps_eq(&other.components, epsilon, max_ulps)
+    }
+}
+
+impl<T> From<Quaternion<T>> for Matrix<T, 3, 3>
+where
+    T: Real,
+{
+    fn from(q: Quaternion<T>) -> Self {
+        let m = q.rotation_rows();
+        Matrix::from_array_array(&[
+            [m[0][0], m[1][0], m[2][0]],
+            [m[0][1], m[1][1], m[2][1]],
+            [m[0][2], m[1][2], m[2][2]],
+        ])
+    }
+}
+
+impl<T> From<Quaternion<T>> for Matrix<T, 4, 4>
+where
+    T: Real,
+{
+    fn from(q: Quaternion<T>) -> Self {
+        let m = q.rotation_rows();
+        let zero = T::zero();
+        Matrix::from_array_array(&[
+            [m[0][0], m[1][0], m[2][0], zero],
+            [m[0][1], m[1][1], m[2][1], zero],
+            [m[0][2], m[1][2], m[2][2], zero],
+            [zero, zero, zero, T::one()],
+        ])
     }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum QuaternionError {
     ZeroLength,
     VectorError(VectorError),
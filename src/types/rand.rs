@@ -1,5 +1,6 @@
 use crate::RandNorm;
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Rand<T: RandNorm> {
     seed: T::SeedType,
 }
@@ -0,0 +1,162 @@
+use crate::*;
+use serde::de::{Error, IgnoredAny, SeqAccess, Visitor};
+use serde::ser::SerializeTuple;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::Formatter;
+use std::marker::PhantomData;
+
+// Vectors are fixed-size sequences of their components
+impl<T: Sized, const N: usize> Serialize for Vector<T, N>
+where
+    T: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut tuple = serializer.serialize_tuple(N)?;
+        for component in self.iter() {
+            tuple.serialize_element(component)?;
+        }
+        tuple.end()
+    }
+}
+
+struct VectorVisitor<T, const N: usize> {
+    marker: PhantomData<T>,
+}
+
+impl<'de, T: Sized, const N: usize> Visitor<'de> for VectorVisitor<T, N>
+where
+    T: Deserialize<'de>,
+{
+    type Value = Vector<T, N>;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "a sequence of {} components", N)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Vector<T, N>, A::Error> {
+        let mut components = Vec::with_capacity(N);
+        for idx in 0..N {
+            match seq.next_element()? {
+                Some(component) => components.push(component),
+                None => return Err(A::Error::invalid_length(idx, &self)),
+            }
+        }
+        if seq.next_element::<IgnoredAny>()?.is_some() {
+            return Err(A::Error::invalid_length(N + 1, &self));
+        }
+        Vector::try_from_iter(components).map_err(|_| A::Error::invalid_length(N, &self))
+    }
+}
+
+impl<'de, T: Sized, const N: usize> Deserialize<'de> for Vector<T, N>
+where
+    T: Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_tuple(
+            N,
+            VectorVisitor {
+                marker: PhantomData,
+            },
+        )
+    }
+}
+
+// Matrices are sequences of their columns
+impl<T: Sized, const COLUMNS: usize, const ROWS: usize> Serialize for Matrix<T, COLUMNS, ROWS>
+where
+    T: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut tuple = serializer.serialize_tuple(COLUMNS)?;
+        for column_idx in 0..COLUMNS {
+            tuple.serialize_element(&self[column_idx])?;
+        }
+        tuple.end()
+    }
+}
+
+impl<'de, T: Sized, const COLUMNS: usize, const ROWS: usize> Deserialize<'de>
+    for Matrix<T, COLUMNS, ROWS>
+where
+    T: Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Matrix::new(Vector::deserialize(deserializer)?))
+    }
+}
+
+// Dynamically sized vectors and matrices use the same representations as the fixed-size ones
+impl<T: Serialize> Serialize for DVector<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.components.serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for DVector<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(DVector::new(Vec::deserialize(deserializer)?))
+    }
+}
+
+impl<T: Serialize> Serialize for DMatrix<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut tuple = serializer.serialize_tuple(self.column_count())?;
+        for column_idx in 0..self.column_count() {
+            tuple.serialize_element(&self[column_idx])?;
+        }
+        tuple.end()
+    }
+}
+
+impl<'de, T: Deserialize<'de> + Clone> Deserialize<'de> for DMatrix<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let columns = Vec::<DVector<T>>::deserialize(deserializer)?;
+        DMatrix::from_columns(&columns).map_err(|error| D::Error::custom(format!("{:?}", error)))
+    }
+}
+
+// Networks are stored as their hyperparameters and flattened weights
+#[derive(Serialize, Deserialize)]
+struct NNetData {
+    learning_rate: f64,
+    hidden_activation: Activation,
+    output_activation: Activation,
+    weights: Vec<f64>,
+}
+
+impl<
+        const INPUTS: usize,
+        const OUTPUTS: usize,
+        const HIDDEN_LAYERS: usize,
+        const HIDDEN_SIZE: usize,
+    > Serialize for NNet<INPUTS, OUTPUTS, HIDDEN_LAYERS, HIDDEN_SIZE>
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        NNetData {
+            learning_rate: self.learning_rate(),
+            hidden_activation: self.hidden_activation(),
+            output_activation: self.output_activation(),
+            weights: self.weights(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<
+        'de,
+        const INPUTS: usize,
+        const OUTPUTS: usize,
+        const HIDDEN_LAYERS: usize,
+        const HIDDEN_SIZE: usize,
+    > Deserialize<'de> for NNet<INPUTS, OUTPUTS, HIDDEN_LAYERS, HIDDEN_SIZE>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let data = NNetData::deserialize(deserializer)?;
+        let mut nnet = NNet::from_weights(&data.weights, data.learning_rate)
+            .map_err(|error| D::Error::custom(format!("{:?}", error)))?;
+        nnet.set_hidden_activation(data.hidden_activation);
+        nnet.set_output_activation(data.output_activation);
+        Ok(nnet)
+    }
+}
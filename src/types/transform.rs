@@ -0,0 +1,101 @@
+use crate::*;
+use std::ops::Mul;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Transform<T = f32> {
+    pub translation: Vector<T, 3>,
+    pub rotation: Quaternion<T>,
+    pub scale: Vector<T, 3>,
+}
+
+impl<T> Transform<T> {
+    pub const fn new(
+        translation: Vector<T, 3>,
+        rotation: Quaternion<T>,
+        scale: Vector<T, 3>,
+    ) -> Self {
+        Self {
+            translation,
+            rotation,
+            scale,
+        }
+    }
+}
+
+impl<T> Transform<T>
+where
+    T: Real,
+{
+    pub fn identity() -> Self {
+        Self::new(Vector::zero(), Quaternion::identity(), Vector::one())
+    }
+
+    pub fn from_translation(translation: Vector<T, 3>) -> Self {
+        Self::new(translation, Quaternion::identity(), Vector::one())
+    }
+
+    pub fn from_rotation(rotation: Quaternion<T>) -> Self {
+        Self::new(Vector::zero(), rotation, Vector::one())
+    }
+
+    pub fn from_scale(scale: Vector<T, 3>) -> Self {
+        Self::new(Vector::zero(), Quaternion::identity(), scale)
+    }
+
+    pub fn from_matrix(matrix: &Matrix<T, 4, 4>) -> Result<Self, MatrixError> {
+        let (translation, rotation, scale) = matrix.decompose()?;
+        Ok(Self::new(translation, rotation, scale))
+    }
+
+    pub fn transform_point(&self, point: Vector<T, 3>) -> Vector<T, 3> {
+        self.translation + self.rotation.rotate_vector(point * self.scale)
+    }
+
+    pub fn transform_vector(&self, vector: Vector<T, 3>) -> Vector<T, 3> {
+        self.rotation.rotate_vector(vector * self.scale)
+    }
+
+    // Exact as long as the scale is uniform, since a rotated non-uniform scale can't be
+    // represented by translation, rotation and scale alone
+    pub fn inverse(&self) -> Self {
+        let scale = Vector::<T, 3>::one() / self.scale;
+        let rotation = self.rotation.conjugate();
+        let translation = -(rotation.rotate_vector(self.translation) * scale);
+        Self::new(translation, rotation, scale)
+    }
+
+    pub fn lerp(&self, other: &Self, t: T) -> Self {
+        let s = T::one() - t;
+        Self::new(
+            self.translation * s + other.translation * t,
+            self.rotation.slerp(&other.rotation, t),
+            self.scale * s + other.scale * t,
+        )
+    }
+}
+
+// Applies the right-hand transform first. Like `inverse`, this is only exact when the left-hand
+// scale is uniform
+impl<T> Mul<Self> for Transform<T>
+where
+    T: Real,
+{
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self::new(
+            self.transform_point(other.translation),
+            self.rotation * other.rotation,
+            self.scale * other.scale,
+        )
+    }
+}
+
+impl<T> From<Transform<T>> for Matrix<T, 4, 4>
+where
+    T: Real,
+{
+    fn from(transform: Transform<T>) -> Self {
+        Matrix::from_trs(transform.translation, transform.rotation, transform.scale)
+    }
+}
@@ -1,6 +1,7 @@
 use crate::*;
 use std::num::Wrapping;
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ValRand {
     base_seed: u64,
 }
@@ -1,20 +1,23 @@
 use std::cmp::{Eq, PartialEq};
 use std::fmt::{Debug, Formatter};
 use std::hash::{Hash, Hasher};
-use std::iter::Sum;
+use std::iter::{Product, Sum};
 use std::ops::{
     Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Rem, RemAssign, Sub,
     SubAssign,
 };
 
-use crate::{init_array, One, Sqrt, Two, Zero};
+use crate::{
+    bytes_of, init_array, Abs, ApproxEq, One, Pod, Real, Signed, Sqrt, Two, Zero, Zeroable,
+};
 
 #[repr(C)]
 pub struct Vector<T: Sized, const N: usize> {
     pub components: [T; N],
 }
 
-//type TruncatedVector<T, const N: usize> = Vector<T, {N - 1}>;
+pub type TruncatedVector<T, const N: usize> = Vector<T, { N - 1 }>;
+pub type ExtendedVector<T, const N: usize> = Vector<T, { N + 1 }>;
 
 impl<T: Sized, const N: usize> Vector<T, N> {
     pub const fn new(components: [T; N]) -> Self
@@ -46,14 +49,11 @@ impl<T: Sized, const N: usize> Vector<T, N> {
 
     pub fn normalized(&self) -> Result<Self, VectorError>
     where
-        T: Add<T, Output = T> + Sqrt<Output = T> + Clone,
-        T: Mul<T, Output = T>,
-        T: Div<T, Output = T>,
+        T: Real,
     {
         let length = self.length()?;
         Ok(Self::new(init_array!([T; N], |idx| {
-            let component: &T = &self.components[idx];
-            component.clone() / length.clone()
+            self.components[idx] / length
         })))
     }
 
@@ -73,14 +73,148 @@ impl<T: Sized, const N: usize> Vector<T, N> {
         }
     }
 
+    pub fn swizzle<const M: usize>(&self, indices: [usize; M]) -> Result<Vector<T, M>, VectorError>
+    where
+        T: Clone,
+    {
+        if indices.iter().any(|&idx| idx >= N) {
+            return Err(VectorError::NoComponentWithGivenIndex);
+        }
+        Ok(Vector::new(init_array!([T; M], |idx| {
+            let component: &T = &self.components[indices[idx]];
+            component.clone()
+        })))
+    }
+
+    // Indices below N select from self, indices from N up to 2N select from other
+    pub fn shuffle<const M: usize>(
+        &self,
+        other: &Self,
+        indices: [usize; M],
+    ) -> Result<Vector<T, M>, VectorError>
+    where
+        T: Clone,
+    {
+        if indices.iter().any(|&idx| idx >= N * 2) {
+            return Err(VectorError::NoComponentWithGivenIndex);
+        }
+        Ok(Vector::new(init_array!([T; M], |idx| {
+            let component: &T = if indices[idx] < N {
+                &self.components[indices[idx]]
+            } else {
+                &other.components[indices[idx] - N]
+            };
+            component.clone()
+        })))
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        &self.components
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.components
+    }
+
+    pub fn as_ptr(&self) -> *const T {
+        self.components.as_ptr()
+    }
+
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self.components.as_mut_ptr()
+    }
+
+    pub fn as_bytes(&self) -> &[u8]
+    where
+        T: Pod,
+    {
+        bytes_of(self)
+    }
+
+    pub fn from_slice(components: &[T]) -> Result<Self, VectorError>
+    where
+        T: Clone,
+    {
+        Self::try_from_iter(components.iter().cloned())
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.components.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.components.iter_mut()
+    }
+
+    // Fails unless the iterator yields exactly N components. At most N + 1 items are pulled
+    pub fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> Result<Self, VectorError> {
+        let mut iter = iter.into_iter();
+        let components = Vector::new(init_array!([Option<T>; N], mut |_| iter.next()));
+        if components.iter().any(Option::is_none) || iter.next().is_some() {
+            return Err(VectorError::IncorrectComponentCount);
+        }
+        Ok(components.map(Option::unwrap))
+    }
+
+    pub fn map<U, F: FnMut(T) -> U>(self, mut f: F) -> Vector<U, N> {
+        let mut components = self.into_iter();
+        Vector::new(init_array!([U; N], mut |_| f(components.next().unwrap())))
+    }
+
+    pub fn map_with_index<U, F: FnMut(usize, T) -> U>(self, mut f: F) -> Vector<U, N> {
+        let mut components = self.into_iter();
+        Vector::new(init_array!([U; N], mut |idx| f(idx, components.next().unwrap())))
+    }
+
+    pub fn zip_with<U, V, F: FnMut(T, U) -> V>(
+        self,
+        other: Vector<U, N>,
+        mut f: F,
+    ) -> Vector<V, N> {
+        let mut components = self.into_iter().zip(other);
+        Vector::new(init_array!([V; N], mut |_| {
+            let (a, b) = components.next().unwrap();
+            f(a, b)
+        }))
+    }
+
+    pub fn zip3_with<U, V, W, F: FnMut(T, U, V) -> W>(
+        self,
+        second: Vector<U, N>,
+        third: Vector<V, N>,
+        mut f: F,
+    ) -> Vector<W, N> {
+        let mut components = self.into_iter().zip(second).zip(third);
+        Vector::new(init_array!([W; N], mut |_| {
+            let ((a, b), c) = components.next().unwrap();
+            f(a, b, c)
+        }))
+    }
+
+    pub fn fold<A, F: FnMut(A, T) -> A>(self, init: A, f: F) -> A {
+        self.into_iter().fold(init, f)
+    }
+
+    pub fn reduce<F: FnMut(T, T) -> T>(self, f: F) -> Result<T, VectorError> {
+        let mut components = self.into_iter();
+        let first = components.next().ok_or(VectorError::ZeroComponents)?;
+        Ok(components.fold(first, f))
+    }
+
+    pub fn any<F: FnMut(&T) -> bool>(&self, f: F) -> bool {
+        self.iter().any(f)
+    }
+
+    pub fn all<F: FnMut(&T) -> bool>(&self, f: F) -> bool {
+        self.iter().all(f)
+    }
+
     pub fn add_vector(&mut self, other: &Self)
     where
         T: Add<T, Output = T> + Clone,
     {
-        for idx in 0..N {
-            let a = self.components[idx].clone();
-            let b = other.components[idx].clone();
-            self.components[idx] = a + b;
+        for (a, b) in self.iter_mut().zip(other) {
+            *a = a.clone() + b.clone();
         }
     }
 
@@ -88,10 +222,8 @@ impl<T: Sized, const N: usize> Vector<T, N> {
     where
         T: Sub<T, Output = T> + Clone,
     {
-        for idx in 0..N {
-            let a = self.components[idx].clone();
-            let b = other.components[idx].clone();
-            self.components[idx] = a - b;
+        for (a, b) in self.iter_mut().zip(other) {
+            *a = a.clone() - b.clone();
         }
     }
 
@@ -99,10 +231,8 @@ impl<T: Sized, const N: usize> Vector<T, N> {
     where
         T: Mul<T, Output = T> + Clone,
     {
-        for idx in 0..N {
-            let a = self.components[idx].clone();
-            let b = other.components[idx].clone();
-            self.components[idx] = a * b;
+        for (a, b) in self.iter_mut().zip(other) {
+            *a = a.clone() * b.clone();
         }
     }
 
@@ -110,10 +240,8 @@ impl<T: Sized, const N: usize> Vector<T, N> {
     where
         T: Div<T, Output = T> + Clone,
     {
-        for idx in 0..N {
-            let a = self.components[idx].clone();
-            let b = other.components[idx].clone();
-            self.components[idx] = a / b;
+        for (a, b) in self.iter_mut().zip(other) {
+            *a = a.clone() / b.clone();
         }
     }
 
@@ -121,10 +249,8 @@ impl<T: Sized, const N: usize> Vector<T, N> {
     where
         T: Rem<T, Output = T> + Clone,
     {
-        for idx in 0..N {
-            let a = self.components[idx].clone();
-            let b = other.components[idx].clone();
-            self.components[idx] = a % b;
+        for (a, b) in self.iter_mut().zip(other) {
+            *a = a.clone() % b.clone();
         }
     }
 
@@ -132,10 +258,8 @@ impl<T: Sized, const N: usize> Vector<T, N> {
     where
         T: Add<T, Output = T> + Clone,
     {
-        for idx in 0..N {
-            let a = self.components[idx].clone();
-            let b = other.clone();
-            self.components[idx] = a + b;
+        for a in self.iter_mut() {
+            *a = a.clone() + other.clone();
         }
     }
 
@@ -143,10 +267,8 @@ impl<T: Sized, const N: usize> Vector<T, N> {
     where
         T: Sub<T, Output = T> + Clone,
     {
-        for idx in 0..N {
-            let a = self.components[idx].clone();
-            let b = other.clone();
-            self.components[idx] = a - b;
+        for a in self.iter_mut() {
+            *a = a.clone() - other.clone();
         }
     }
 
@@ -154,10 +276,8 @@ impl<T: Sized, const N: usize> Vector<T, N> {
     where
         T: Mul<T, Output = T> + Clone,
     {
-        for idx in 0..N {
-            let a = self.components[idx].clone();
-            let b = other.clone();
-            self.components[idx] = a * b;
+        for a in self.iter_mut() {
+            *a = a.clone() * other.clone();
         }
     }
 
@@ -165,10 +285,8 @@ impl<T: Sized, const N: usize> Vector<T, N> {
     where
         T: Div<T, Output = T> + Clone,
     {
-        for idx in 0..N {
-            let a = self.components[idx].clone();
-            let b = other.clone();
-            self.components[idx] = a / b;
+        for a in self.iter_mut() {
+            *a = a.clone() / other.clone();
         }
     }
 
@@ -176,24 +294,80 @@ impl<T: Sized, const N: usize> Vector<T, N> {
     where
         T: Rem<T, Output = T> + Clone,
     {
-        for idx in 0..N {
-            let a = self.components[idx].clone();
-            let b = other.clone();
-            self.components[idx] = a % b;
+        for a in self.iter_mut() {
+            *a = a.clone() % other.clone();
         }
     }
-    /*
+
     pub fn truncate(&self) -> TruncatedVector<T, N>
-        where TruncatedVector<T, N>: Clone,
+    where
         T: Clone,
+        [(); N - 1]:,
     {
-        Vector {
-            components: init_array!([T; N - 1], |idx| {
-                let component: &T = &self.components[idx];
-                component.clone()
-            }),
+        Vector::new(init_array!([T; N - 1], |idx| {
+            let component: &T = &self.components[idx];
+            component.clone()
+        }))
+    }
+
+    pub fn extend(&self, value: T) -> ExtendedVector<T, N>
+    where
+        T: Clone,
+        [(); N + 1]:,
+    {
+        Vector::new(init_array!([T; N + 1], |idx| if idx < N {
+            let component: &T = &self.components[idx];
+            component.clone()
+        } else {
+            value.clone()
+        }))
+    }
+
+    // Drops the trailing components or pads with zeros to reach M components
+    pub fn resize<const M: usize>(&self) -> Vector<T, M>
+    where
+        T: Clone + Zero,
+    {
+        Vector::new(init_array!([T; M], |idx| if idx < N {
+            let component: &T = &self.components[idx];
+            component.clone()
+        } else {
+            T::zero()
+        }))
+    }
+
+    pub fn to_homogeneous_point(&self) -> ExtendedVector<T, N>
+    where
+        T: Clone + One,
+        [(); N + 1]:,
+    {
+        self.extend(T::one())
+    }
+
+    pub fn to_homogeneous_direction(&self) -> ExtendedVector<T, N>
+    where
+        T: Clone + Zero,
+        [(); N + 1]:,
+    {
+        self.extend(T::zero())
+    }
+
+    // Points are divided by their last component, directions (last component zero) are only
+    // truncated
+    pub fn from_homogeneous(&self) -> TruncatedVector<T, N>
+    where
+        T: Div<T, Output = T> + Zero + PartialEq + Clone,
+        [(); N - 1]:,
+    {
+        let w = self.components[N - 1].clone();
+        if w == T::zero() {
+            return self.truncate();
         }
-    }*/
+        Vector::new(init_array!([T; N - 1], |idx| {
+            let component: &T = &self.components[idx];
+            component.clone() / w.clone()
+        }))
+    }
 
     pub fn dot(&self, other: &Self) -> T
     where
@@ -217,10 +391,307 @@ impl<T: Sized, const N: usize> Vector<T, N> {
     }
 }
 
+// Component-wise math. Methods taking a vector have a `_component` variant taking a scalar
+// that is applied to every component
+impl<T: Sized, const N: usize> Vector<T, N> {
+    pub fn splat(value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::new(init_array!([T; N], |_| value.clone()))
+    }
+
+    pub fn min(&self, other: &Self) -> Self
+    where
+        T: PartialOrd + Clone,
+    {
+        self.clone()
+            .zip_with(other.clone(), |a, b| if b < a { b } else { a })
+    }
+
+    pub fn min_component(&self, other: &T) -> Self
+    where
+        T: PartialOrd + Clone,
+    {
+        self.min(&Self::splat(other.clone()))
+    }
+
+    pub fn max(&self, other: &Self) -> Self
+    where
+        T: PartialOrd + Clone,
+    {
+        self.clone()
+            .zip_with(other.clone(), |a, b| if b > a { b } else { a })
+    }
+
+    pub fn max_component(&self, other: &T) -> Self
+    where
+        T: PartialOrd + Clone,
+    {
+        self.max(&Self::splat(other.clone()))
+    }
+
+    pub fn clamp(&self, min: &Self, max: &Self) -> Self
+    where
+        T: PartialOrd + Clone,
+    {
+        self.max(min).min(max)
+    }
+
+    pub fn clamp_component(&self, min: &T, max: &T) -> Self
+    where
+        T: PartialOrd + Clone,
+    {
+        self.max_component(min).min_component(max)
+    }
+
+    pub fn abs(&self) -> Self
+    where
+        T: Abs<Output = T>,
+    {
+        Self::new(init_array!([T; N], |idx: usize| self.components[idx].abs()))
+    }
+
+    pub fn signum(&self) -> Self
+    where
+        T: Signed,
+    {
+        Self::new(init_array!([T; N], |idx: usize| self.components[idx].signum()))
+    }
+
+    pub fn floor(&self) -> Self
+    where
+        T: Real,
+    {
+        self.map(|component| component.floor())
+    }
+
+    pub fn ceil(&self) -> Self
+    where
+        T: Real,
+    {
+        self.map(|component| component.ceil())
+    }
+
+    pub fn round(&self) -> Self
+    where
+        T: Real,
+    {
+        self.map(|component| component.round())
+    }
+
+    pub fn fract(&self) -> Self
+    where
+        T: Real,
+    {
+        self.map(|component| component.fract())
+    }
+
+    pub fn recip(&self) -> Self
+    where
+        T: Real,
+    {
+        self.map(|component| component.recip())
+    }
+
+    pub fn sqrt(&self) -> Self
+    where
+        T: Sqrt<Output = T>,
+    {
+        Self::new(init_array!([T; N], |idx: usize| self.components[idx].sqrt()))
+    }
+
+    pub fn pow(&self, exponent: &Self) -> Self
+    where
+        T: Real,
+    {
+        self.zip_with(*exponent, |component, exponent| component.powf(&exponent))
+    }
+
+    pub fn pow_component(&self, exponent: &T) -> Self
+    where
+        T: Real,
+    {
+        self.map(|component| component.powf(exponent))
+    }
+
+    pub fn exp(&self) -> Self
+    where
+        T: Real,
+    {
+        self.map(|component| component.exp())
+    }
+
+    pub fn ln(&self) -> Self
+    where
+        T: Real,
+    {
+        self.map(|component| component.ln())
+    }
+
+    pub fn lerp(&self, other: &Self, t: T) -> Self
+    where
+        T: Real,
+    {
+        self.zip_with(*other, |a, b| a + (b - a) * t)
+    }
+
+    pub fn lerp_vector(&self, other: &Self, t: &Self) -> Self
+    where
+        T: Real,
+    {
+        self.zip3_with(*other, *t, |a, b, t| a + (b - a) * t)
+    }
+
+    // Zero where the component is below the edge, one otherwise
+    pub fn step(&self, edge: &Self) -> Self
+    where
+        T: PartialOrd + Zero + One + Clone,
+    {
+        self.clone().zip_with(edge.clone(), |component, edge| {
+            if component < edge {
+                T::zero()
+            } else {
+                T::one()
+            }
+        })
+    }
+
+    pub fn step_component(&self, edge: &T) -> Self
+    where
+        T: PartialOrd + Zero + One + Clone,
+    {
+        self.step(&Self::splat(edge.clone()))
+    }
+
+    // Hermite interpolation from zero at the lower edge to one at the upper edge
+    pub fn smoothstep(&self, lower: &Self, upper: &Self) -> Self
+    where
+        T: Real,
+    {
+        self.zip3_with(*lower, *upper, |component, lower, upper| {
+            let t = ((component - lower) / (upper - lower)).clamp(&T::zero(), &T::one());
+            t * t * (T::from(3.0) - T::two() * t)
+        })
+    }
+
+    pub fn smoothstep_component(&self, lower: &T, upper: &T) -> Self
+    where
+        T: Real,
+    {
+        self.smoothstep(&Self::splat(*lower), &Self::splat(*upper))
+    }
+
+    pub fn min_element(&self) -> Result<T, VectorError>
+    where
+        T: PartialOrd + Clone,
+    {
+        self.clone().reduce(|a, b| if b < a { b } else { a })
+    }
+
+    pub fn max_element(&self) -> Result<T, VectorError>
+    where
+        T: PartialOrd + Clone,
+    {
+        self.clone().reduce(|a, b| if b > a { b } else { a })
+    }
+
+    pub fn sum(&self) -> T
+    where
+        T: Add<T, Output = T> + Zero + Clone,
+    {
+        self.clone()
+            .fold(T::zero(), |sum, component| sum + component)
+    }
+
+    pub fn product(&self) -> T
+    where
+        T: Mul<T, Output = T> + One + Clone,
+    {
+        self.clone()
+            .fold(T::one(), |product, component| product * component)
+    }
+}
+
+// Geometry
+impl<T: Sized, const N: usize> Vector<T, N>
+where
+    T: Real,
+{
+    pub fn distance2(&self, other: &Self) -> Result<T, VectorError> {
+        (*other - *self).length2()
+    }
+
+    pub fn distance(&self, other: &Self) -> Result<T, VectorError> {
+        (*other - *self).length()
+    }
+
+    // Fails when the length is not above epsilon
+    pub fn try_normalized(&self, epsilon: T) -> Result<Self, VectorError> {
+        let length: T = self.length()?;
+        if length <= epsilon {
+            return Err(VectorError::ZeroLength);
+        }
+        Ok(*self / length)
+    }
+
+    pub fn normalize_or_zero(&self) -> Self {
+        self.try_normalized(T::epsilon())
+            .unwrap_or_else(|_| Self::zero())
+    }
+
+    pub fn clamp_length(&self, min: T, max: T) -> Self {
+        match self.length() {
+            Ok(length) if length < min => self.normalize_or_zero() * min,
+            Ok(length) if length > max => *self * (max / length),
+            _ => *self,
+        }
+    }
+
+    pub fn project_onto(&self, other: &Self) -> Result<Self, VectorError> {
+        let length2 = other.length2()?;
+        if length2 <= T::epsilon() {
+            return Err(VectorError::ZeroLength);
+        }
+        Ok(*other * (self.dot(other) / length2))
+    }
+
+    pub fn reject_from(&self, other: &Self) -> Result<Self, VectorError> {
+        Ok(*self - self.project_onto(other)?)
+    }
+
+    // The normal is expected to be normalized
+    pub fn reflect(&self, normal: &Self) -> Self {
+        *self - *normal * (T::two() * self.dot(normal))
+    }
+
+    // The vector and normal are expected to be normalized, eta is the ratio of the refractive
+    // indices. Returns None on total internal reflection
+    pub fn refract(&self, normal: &Self, eta: T) -> Option<Self> {
+        let cos = self.dot(normal);
+        let k = T::one() - eta * eta * (T::one() - cos * cos);
+        if k < T::zero() {
+            return None;
+        }
+        Some(*self * eta - *normal * (eta * cos + k.sqrt()))
+    }
+
+    // Angle in radians, using the half-angle formula which stays precise near 0 and pi
+    pub fn angle_between(&self, other: &Self) -> Result<T, VectorError> {
+        let a = self.try_normalized(T::epsilon())?;
+        let b = other.try_normalized(T::epsilon())?;
+        Ok(T::two() * (a - b).length()?.atan2(&(a + b).length()?))
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum VectorError {
     ZeroComponents,
     NoComponentWithGivenIndex,
+    IncorrectComponentCount,
+    ZeroLength,
+    DimensionMismatch,
 }
 
 #[macro_export]
@@ -285,6 +756,43 @@ where
     }
 }
 
+impl<T: Sized, const N: usize> ApproxEq for Vector<T, N>
+where
+    T: ApproxEq,
+{
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> T::Epsilon {
+        T::default_epsilon()
+    }
+
+    fn default_max_relative() -> T::Epsilon {
+        T::default_max_relative()
+    }
+
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: T::Epsilon) -> bool {
+        self.iter()
+            .zip(other)
+            .all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: T::Epsilon, max_relative: T::Epsilon) -> bool {
+        self.iter()
+            .zip(other)
+            .all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+        self.iter()
+            .zip(other)
+            .all(|(a, b)| a.ulps_eq(b, epsilon, max_ulps))
+    }
+}
+
 impl<T: Sized, const N: usize> Default for Vector<T, N>
 where
     T: Default,
@@ -294,6 +802,15 @@ where
     }
 }
 
+unsafe impl<T: Zeroable, const N: usize> Zeroable for Vector<T, N> {}
+unsafe impl<T: Pod, const N: usize> Pod for Vector<T, N> {}
+
+crate::assert_layout!(Vector<f32, 2>, size: 8, align: 4);
+crate::assert_layout!(Vector<f32, 3>, size: 12, align: 4);
+crate::assert_layout!(Vector<f32, 4>, size: 16, align: 4);
+crate::assert_layout!(Vector<f64, 3>, size: 24, align: 8);
+crate::assert_layout!(Vector<u8, 4>, size: 4, align: 1);
+
 impl<T: Sized, const N: usize> Zero for Vector<T, N>
 where
     T: Zero,
@@ -323,12 +840,12 @@ where
 
 impl<T: Sized, const N: usize> Neg for Vector<T, N>
 where
-    T: Neg + Clone,
+    T: Neg,
 {
     type Output = Vector<<T as Neg>::Output, N>;
 
     fn neg(self) -> Vector<<T as Neg>::Output, N> {
-        Vector::new(init_array!([<T as Neg>::Output; N], |idx| -self[idx].clone()))
+        self.map(|component| -component)
     }
 }
 
@@ -368,6 +885,69 @@ impl<T: Sized, const N: usize> IndexMut<usize> for Vector<T, N> {
     }
 }
 
+impl<T: Sized, const N: usize> IntoIterator for Vector<T, N> {
+    type Item = T;
+    type IntoIter = std::array::IntoIter<T, N>;
+
+    fn into_iter(self) -> std::array::IntoIter<T, N> {
+        std::array::IntoIter::new(self.components)
+    }
+}
+
+impl<'a, T: Sized, const N: usize> IntoIterator for &'a Vector<T, N> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> std::slice::Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T: Sized, const N: usize> IntoIterator for &'a mut Vector<T, N> {
+    type Item = &'a mut T;
+    type IntoIter = std::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> std::slice::IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+impl<T: Sized, const N: usize> Sum for Vector<T, N>
+where
+    T: Add<T, Output = T> + Zero + Clone,
+{
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |sum, vector| sum + vector)
+    }
+}
+
+impl<'a, T: Sized, const N: usize> Sum<&'a Vector<T, N>> for Vector<T, N>
+where
+    T: Add<T, Output = T> + Zero + Clone,
+{
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |sum, vector| sum + vector)
+    }
+}
+
+impl<T: Sized, const N: usize> Product for Vector<T, N>
+where
+    T: Mul<T, Output = T> + One + Clone,
+{
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::one(), |product, vector| product * vector)
+    }
+}
+
+impl<'a, T: Sized, const N: usize> Product<&'a Vector<T, N>> for Vector<T, N>
+where
+    T: Mul<T, Output = T> + One + Clone,
+{
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::one(), |product, vector| product * vector)
+    }
+}
+
 macro_rules! vector_binary_op {
     ($op:ident, $fn_name:ident, $type_method_component:ident, $type_method:ident) => {
         impl<'a, 'b, T: Sized, const N: usize> $op<&'b Vector<T, N>> for &'a Vector<T, N>
@@ -600,6 +1180,14 @@ pub trait VectorXY<T> {
     fn x_mut(&mut self) -> &mut T;
     fn y(&self) -> &T;
     fn y_mut(&mut self) -> &mut T;
+
+    // Rotated a quarter turn counter-clockwise
+    fn perpendicular(&self) -> Vector<T, 2>
+    where
+        T: Neg<Output = T> + Clone,
+    {
+        vector!(-self.y().clone(), self.x().clone())
+    }
 }
 
 pub trait VectorXYZ<T> {
@@ -627,29 +1215,36 @@ pub trait VectorXYZ<T> {
         )
     }
 
+    // Two unit vectors that form a right-handed orthonormal basis with this one, which is
+    // expected to be normalized (Duff et al. 2017)
+    fn any_orthonormal_basis(&self) -> (Vector<T, 3>, Vector<T, 3>)
+    where
+        T: Real,
+    {
+        let (x, y, z) = (*self.x(), *self.y(), *self.z());
+        let sign = if z < T::zero() { -T::one() } else { T::one() };
+        let a = -T::one() / (sign + z);
+        let b = x * y * a;
+        (
+            vector!(T::one() + sign * x * x * a, sign * b, -sign * x),
+            vector!(b, sign + y * y * a, -y),
+        )
+    }
+
     fn right(forward: Self, up: Self) -> Result<Self, VectorError>
     where
         Self: Sized,
-        T: Add<T, Output = T> + Sqrt<Output = T> + Clone,
-        T: Mul<T, Output = T>,
-        T: Div<T, Output = T>,
-        T: Sub<T, Output = T>;
+        T: Real;
 
     fn up(right: Self, forward: Self) -> Result<Self, VectorError>
     where
         Self: Sized,
-        T: Add<T, Output = T> + Sqrt<Output = T> + Clone,
-        T: Mul<T, Output = T>,
-        T: Div<T, Output = T>,
-        T: Sub<T, Output = T>;
+        T: Real;
 
     fn forward(up: Self, right: Self) -> Result<Self, VectorError>
     where
         Self: Sized,
-        T: Add<T, Output = T> + Sqrt<Output = T> + Clone,
-        T: Mul<T, Output = T>,
-        T: Div<T, Output = T>,
-        T: Sub<T, Output = T>;
+        T: Real;
 }
 
 pub trait VectorXYZW<T> {
@@ -702,6 +1297,8 @@ impl<T> VectorXY<T> for Vector<T, 2> {
     }
 }
 
+// The swizzle methods below forward to the generated inherent ones in swizzle.rs, which take
+// precedence on concrete vectors; the trait versions serve generic code
 impl<T> VectorXYZ<T> for Vector<T, 3> {
     fn x(&self) -> &T {
         &self.components[0]
@@ -725,22 +1322,19 @@ impl<T> VectorXYZ<T> for Vector<T, 3> {
     where
         T: Clone,
     {
-        vector!(self.x().clone(), self.y().clone())
+        Vector::<T, 3>::xy(self)
     }
     fn yz(&self) -> Vector<T, 2>
     where
         T: Clone,
     {
-        vector!(self.y().clone(), self.z().clone())
+        Vector::<T, 3>::yz(self)
     }
 
     fn right(forward: Self, up: Self) -> Result<Self, VectorError>
     where
         Self: Sized,
-        T: Add<T, Output = T> + Sqrt<Output = T> + Clone,
-        T: Mul<T, Output = T>,
-        T: Div<T, Output = T>,
-        T: Sub<T, Output = T>,
+        T: Real,
     {
         Ok(forward.normalized()?.cross(&up.normalized()?))
     }
@@ -748,10 +1342,7 @@ impl<T> VectorXYZ<T> for Vector<T, 3> {
     fn up(right: Self, forward: Self) -> Result<Self, VectorError>
     where
         Self: Sized,
-        T: Add<T, Output = T> + Sqrt<Output = T> + Clone,
-        T: Mul<T, Output = T>,
-        T: Div<T, Output = T>,
-        T: Sub<T, Output = T>,
+        T: Real,
     {
         Ok(right.normalized()?.cross(&forward.normalized()?))
     }
@@ -759,10 +1350,7 @@ impl<T> VectorXYZ<T> for Vector<T, 3> {
     fn forward(up: Self, right: Self) -> Result<Self, VectorError>
     where
         Self: Sized,
-        T: Add<T, Output = T> + Sqrt<Output = T> + Clone,
-        T: Mul<T, Output = T>,
-        T: Div<T, Output = T>,
-        T: Sub<T, Output = T>,
+        T: Real,
     {
         Ok(up.normalized()?.cross(&right.normalized()?))
     }
@@ -797,30 +1385,30 @@ impl<T> VectorXYZW<T> for Vector<T, 4> {
     where
         T: Clone,
     {
-        vector!(self.x().clone(), self.y().clone())
+        Vector::<T, 4>::xy(self)
     }
     fn yz(&self) -> Vector<T, 2>
     where
         T: Clone,
     {
-        vector!(self.y().clone(), self.z().clone())
+        Vector::<T, 4>::yz(self)
     }
     fn zw(&self) -> Vector<T, 2>
     where
         T: Clone,
     {
-        vector!(self.z().clone(), self.w().clone())
+        Vector::<T, 4>::zw(self)
     }
     fn xyz(&self) -> Vector<T, 3>
     where
         T: Clone,
     {
-        vector!(self.x().clone(), self.y().clone(), self.z().clone())
+        Vector::<T, 4>::xyz(self)
     }
     fn yzw(&self) -> Vector<T, 3>
     where
         T: Clone,
     {
-        vector!(self.y().clone(), self.z().clone(), self.w().clone())
+        Vector::<T, 4>::yzw(self)
     }
 }
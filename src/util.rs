@@ -42,3 +42,63 @@ macro_rules! count_args {
         [$(stringify!($arg)),+].len()
     }
 }
+
+#[macro_export]
+macro_rules! assert_approx_eq {
+    ($left:expr, $right:expr $(,)?) => {{
+        let (left, right) = (&$left, &$right);
+        if !$crate::ApproxEq::approx_eq(left, right) {
+            panic!(
+                "assertion failed: `left ≈ right`\n  left: `{:?}`\n right: `{:?}`",
+                left, right
+            );
+        }
+    }};
+    ($left:expr, $right:expr, $epsilon:expr $(,)?) => {{
+        let (left, right) = (&$left, &$right);
+        if !$crate::ApproxEq::abs_diff_eq(left, right, $epsilon) {
+            panic!(
+                "assertion failed: `left ≈ right` (epsilon: `{:?}`)\n  left: `{:?}`\n right: `{:?}`",
+                $epsilon, left, right
+            );
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! assert_vector_approx_eq {
+    ($left:expr, $right:expr $(,)?) => {{
+        let (left, right) = (&$left, &$right);
+        if left.components.len() != right.components.len() {
+            panic!(
+                "assertion failed: `left ≈ right` (lengths {} and {})\n  left: `{:?}`\n right: `{:?}`",
+                left.components.len(), right.components.len(), left, right
+            );
+        }
+        for idx in 0..left.components.len() {
+            if !$crate::ApproxEq::approx_eq(&left[idx], &right[idx]) {
+                panic!(
+                    "assertion failed: `left ≈ right` (component {})\n  left: `{:?}`\n right: `{:?}`",
+                    idx, left, right
+                );
+            }
+        }
+    }};
+    ($left:expr, $right:expr, $epsilon:expr $(,)?) => {{
+        let (left, right) = (&$left, &$right);
+        if left.components.len() != right.components.len() {
+            panic!(
+                "assertion failed: `left ≈ right` (lengths {} and {})\n  left: `{:?}`\n right: `{:?}`",
+                left.components.len(), right.components.len(), left, right
+            );
+        }
+        for idx in 0..left.components.len() {
+            if !$crate::ApproxEq::abs_diff_eq(&left[idx], &right[idx], $epsilon) {
+                panic!(
+                    "assertion failed: `left ≈ right` (component {}, epsilon: `{:?}`)\n  left: `{:?}`\n right: `{:?}`",
+                    idx, $epsilon, left, right
+                );
+            }
+        }
+    }};
+}